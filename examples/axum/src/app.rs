@@ -1,27 +1,12 @@
-use std::ops::Deref;
-
 use crate::{analytics::AnalyticsRepository, posts::PostsRepository};
 use rustyinject::injector::factories::RefConstructorFactory;
 
+#[derive(RefConstructorFactory)]
 pub struct App<'a> {
     posts_repository: &'a dyn PostsRepository,
     analytics_repository: &'a dyn AnalyticsRepository,
 }
 
-impl<'a> RefConstructorFactory<'a> for App<'a> {
-    type Dependencies = (
-        &'a Box<dyn PostsRepository>,
-        (&'a Box<dyn AnalyticsRepository>, ()),
-    );
-
-    fn build(dependencies: Self::Dependencies) -> Self {
-        Self {
-            posts_repository: dependencies.0.deref(),
-            analytics_repository: dependencies.1 .0.deref(),
-        }
-    }
-}
-
 impl<'a> App<'a> {
     pub async fn create_post(&self, title: String, text: String) -> usize {
         let post_id = self.posts_repository.create(title, text).await;
@@ -10,13 +10,7 @@ use axum::{
     Json, Router,
 };
 use posts::{InMemoryPostsRepository, PostsRepository};
-use rustyinject::{
-    injector::{
-        containers::{RefConstructorFactoryContainer, SingletonContainer},
-        Injector,
-    },
-    DependencyContainer,
-};
+use rustyinject::{container, injector::Injector};
 use serde::Deserialize;
 use tokio::net::TcpListener;
 
@@ -24,26 +18,21 @@ mod analytics;
 mod app;
 mod posts;
 
-type Container<'a> = DependencyContainer<
-    (),
-    (
-        RefConstructorFactoryContainer<App<'a>>,
-        (
-            SingletonContainer<Box<dyn AnalyticsRepository>>,
-            (SingletonContainer<Box<dyn PostsRepository>>, ()),
-        ),
-    ),
->;
+container! {
+    type Container<'a>;
+
+    fn new_container() {
+        binding posts_repository: dyn PostsRepository =
+            Box::<InMemoryPostsRepository>::default(),
+        binding analytics_repository: dyn AnalyticsRepository =
+            Box::<InMemoryAnalyticRepository>::default(),
+        ref_constructor App<'a>,
+    }
+}
 
 #[tokio::main]
 async fn main() {
-    let posts_repository: Box<dyn PostsRepository> = Box::<InMemoryPostsRepository>::default();
-    let analytics_repository: Box<dyn AnalyticsRepository> =
-        Box::<InMemoryAnalyticRepository>::default();
-    let container: Container = DependencyContainer::default()
-        .with_singleton(posts_repository)
-        .with_singleton(analytics_repository)
-        .with_ref_constructor_factory::<App>();
+    let container = new_container();
 
     let routes = Router::new()
         .route("/posts", post(create_post))
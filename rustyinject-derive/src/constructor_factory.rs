@@ -0,0 +1,50 @@
+use crate::fields::{list_pattern, list_type, Dependency, FieldSource, InjectField};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = InjectField::parse_all(input)?;
+
+    let mut dependencies = Vec::new();
+    let mut values = Vec::new();
+    for field in &fields {
+        let member = &field.member;
+        let ty = field.ty;
+
+        let value = match &field.source {
+            FieldSource::Injected => {
+                let dependency = Dependency::new(dependencies.len(), quote!(#ty));
+                let binding = dependency.binding.clone();
+                dependencies.push(dependency);
+                quote!(#binding)
+            }
+            FieldSource::Cloned => {
+                let dependency = Dependency::new(dependencies.len(), quote!(&'__dependencies #ty));
+                let binding = dependency.binding.clone();
+                dependencies.push(dependency);
+                quote!(::core::clone::Clone::clone(#binding))
+            }
+            FieldSource::Default => quote!(::core::default::Default::default()),
+            FieldSource::Skipped(expr) => quote!(#expr),
+        };
+        values.push(quote!(#member: #value));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let dependencies_type = list_type(&dependencies);
+    let dependencies_pattern = list_pattern(&dependencies);
+
+    Ok(quote! {
+        impl #impl_generics ::rustyinject::injector::factories::ConstructorFactory
+            for #name #ty_generics #where_clause
+        {
+            type Dependencies<'__dependencies> = #dependencies_type;
+
+            fn build(#dependencies_pattern: Self::Dependencies<'_>) -> Self {
+                Self { #(#values),* }
+            }
+        }
+    })
+}
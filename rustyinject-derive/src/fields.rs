@@ -0,0 +1,119 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Expr, Field, Ident, Index, Member, Type};
+
+/// The way a field of the struct is created.
+pub(crate) enum FieldSource {
+    /// Injected from the container.
+    Injected,
+    /// Injected as an immutable reference and cloned.
+    Cloned,
+    /// Created with [`Default::default`].
+    Default,
+    /// Created with the expression from the `skip` option.
+    Skipped(Expr),
+}
+
+/// A field of the struct that derives a factory.
+pub(crate) struct InjectField<'a> {
+    pub(crate) member: Member,
+    pub(crate) ty: &'a Type,
+    pub(crate) source: FieldSource,
+}
+
+impl<'a> InjectField<'a> {
+    /// Collect fields of the struct with their `inject` attributes parsed.
+    pub(crate) fn parse_all(input: &'a DeriveInput) -> syn::Result<Vec<Self>> {
+        let Data::Struct(data) = &input.data else {
+            return Err(Error::new(
+                Span::call_site(),
+                "factories can only be derived for structs",
+            ));
+        };
+
+        data.fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| Self::parse(index, field))
+            .collect()
+    }
+
+    fn parse(index: usize, field: &'a Field) -> syn::Result<Self> {
+        let member = field.ident.clone().map_or_else(
+            || {
+                Member::Unnamed(Index {
+                    index: u32::try_from(index).unwrap_or(u32::MAX),
+                    span: Span::call_site(),
+                })
+            },
+            Member::Named,
+        );
+
+        let mut source = None;
+        for attribute in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("inject"))
+        {
+            attribute.parse_nested_meta(|meta| {
+                let parsed = if meta.path.is_ident("clone") {
+                    FieldSource::Cloned
+                } else if meta.path.is_ident("default") {
+                    FieldSource::Default
+                } else if meta.path.is_ident("skip") {
+                    FieldSource::Skipped(meta.value()?.parse()?)
+                } else {
+                    return Err(meta.error("expected `clone`, `default` or `skip = ...`"));
+                };
+
+                if source.replace(parsed).is_some() {
+                    return Err(meta.error("only one `inject` option can be specified per field"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(Self {
+            member,
+            ty: &field.ty,
+            source: source.unwrap_or(FieldSource::Injected),
+        })
+    }
+}
+
+/// A dependency of the factory and a binding it gets destructured into.
+pub(crate) struct Dependency {
+    pub(crate) ty: TokenStream,
+    pub(crate) binding: Ident,
+}
+
+impl Dependency {
+    pub(crate) fn new(index: usize, ty: TokenStream) -> Self {
+        Self {
+            ty,
+            binding: format_ident!("dependency_{}", index),
+        }
+    }
+}
+
+/// Build the heterogeneously-typed list type of the dependencies.
+pub(crate) fn list_type(dependencies: &[Dependency]) -> TokenStream {
+    dependencies
+        .iter()
+        .rev()
+        .fold(quote!(()), |tail, dependency| {
+            let ty = &dependency.ty;
+            quote!((#ty, #tail))
+        })
+}
+
+/// Build the pattern destructuring the heterogeneously-typed list of the dependencies.
+pub(crate) fn list_pattern(dependencies: &[Dependency]) -> TokenStream {
+    dependencies
+        .iter()
+        .rev()
+        .fold(quote!(()), |tail, dependency| {
+            let binding = &dependency.binding;
+            quote!((#binding, #tail))
+        })
+}
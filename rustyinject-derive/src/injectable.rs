@@ -0,0 +1,127 @@
+use crate::fields::{list_pattern, list_type, Dependency};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    visit_mut::{self, VisitMut},
+    Error, FnArg, ImplItem, ImplItemFn, ItemImpl, Lifetime, TypeReference,
+};
+
+pub(crate) fn expand(item: &ItemImpl) -> syn::Result<TokenStream> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(Error::new_spanned(
+            path,
+            "`injectable` can only be placed on inherent `impl` blocks",
+        ));
+    }
+    let constructor = find_constructor(item)?;
+
+    let lifetime = item
+        .generics
+        .lifetimes()
+        .next()
+        .map(|param| param.lifetime.clone());
+    let dependencies_lifetime = lifetime
+        .clone()
+        .unwrap_or_else(|| Lifetime::new("'__dependencies", Span::call_site()));
+
+    let mut dependencies = Vec::new();
+    for argument in &constructor.sig.inputs {
+        let FnArg::Typed(argument) = argument else {
+            return Err(Error::new_spanned(
+                argument,
+                "constructor of an injectable type can't take `self`",
+            ));
+        };
+
+        let mut ty = (*argument.ty).clone();
+        ElidedLifetimes(&dependencies_lifetime).visit_type_mut(&mut ty);
+        dependencies.push(Dependency::new(dependencies.len(), quote!(#ty)));
+    }
+
+    let self_ty = &item.self_ty;
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let constructor_name = &constructor.sig.ident;
+    let bindings = dependencies.iter().map(|dependency| &dependency.binding);
+    let dependencies_type = list_type(&dependencies);
+    let dependencies_pattern = list_pattern(&dependencies);
+
+    let factory = if let Some(lifetime) = lifetime {
+        quote! {
+            impl #impl_generics ::rustyinject::injector::factories::RefConstructorFactory<#lifetime>
+                for #self_ty #where_clause
+            {
+                type Dependencies = #dependencies_type;
+
+                fn build(#dependencies_pattern: Self::Dependencies) -> Self {
+                    Self::#constructor_name(#(#bindings),*)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics ::rustyinject::injector::factories::ConstructorFactory
+                for #self_ty #where_clause
+            {
+                type Dependencies<#dependencies_lifetime> = #dependencies_type;
+
+                fn build(#dependencies_pattern: Self::Dependencies<'_>) -> Self {
+                    Self::#constructor_name(#(#bindings),*)
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #item
+        #factory
+    })
+}
+
+/// Find the `new` function of the `impl` block and check that it can be used as a constructor.
+fn find_constructor(item: &ItemImpl) -> syn::Result<&ImplItemFn> {
+    let constructor = item
+        .items
+        .iter()
+        .find_map(|impl_item| {
+            if let ImplItem::Fn(function) = impl_item {
+                (function.sig.ident == "new").then_some(function)
+            } else {
+                None
+            }
+        })
+        .ok_or_else(|| {
+            Error::new_spanned(
+                &item.self_ty,
+                "`injectable` requires the `impl` block to have a `new` function",
+            )
+        })?;
+
+    if !constructor.sig.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &constructor.sig.generics,
+            "constructor of an injectable type can't have generic parameters",
+        ));
+    }
+
+    Ok(constructor)
+}
+
+/// Replaces lifetimes of references without one and anonymous `'_` lifetimes with the lifetime of
+/// the dependencies. Lifetimes elided in paths can't be told apart from types without lifetime
+/// parameters, so they're left as is.
+struct ElidedLifetimes<'a>(&'a Lifetime);
+
+impl VisitMut for ElidedLifetimes<'_> {
+    fn visit_type_reference_mut(&mut self, i: &mut TypeReference) {
+        if i.lifetime.is_none() {
+            i.lifetime = Some(self.0.clone());
+        }
+        visit_mut::visit_type_reference_mut(self, i);
+    }
+
+    fn visit_lifetime_mut(&mut self, i: &mut Lifetime) {
+        if i.ident == "_" {
+            *i = self.0.clone();
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Derive macros for [rustyinject](https://docs.rs/rustyinject).
+//!
+//! Don't depend on this crate directly, enable the `derive` feature of `rustyinject` instead. The
+//! macros are re-exported there next to the traits they implement.
+
+#![deny(
+    warnings,
+    clippy::correctness,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf,
+    clippy::style,
+    clippy::pedantic,
+    clippy::restriction,
+    clippy::cargo
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::blanket_clippy_restriction_lints,
+    clippy::missing_inline_in_public_items,
+    clippy::single_char_lifetime_names,
+    clippy::implicit_return,
+    clippy::pattern_type_mismatch,
+    clippy::question_mark_used,
+    clippy::shadow_reuse,
+    clippy::shadow_same,
+    clippy::pub_with_shorthand,
+    clippy::absolute_paths,
+    clippy::exhaustive_enums,
+    clippy::exhaustive_structs,
+    clippy::multiple_crate_versions,
+    clippy::missing_docs_in_private_items,
+    clippy::pub_use,
+    clippy::infinite_loop, // Allowed because of bug: https://github.com/rust-lang/rust-clippy/issues/12338
+    clippy::unseparated_literal_suffix,
+    clippy::self_named_module_files,
+    clippy::big_endian_bytes,
+    clippy::single_call_fn,
+    clippy::missing_trait_methods,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::print_stdout,
+    clippy::shadow_unrelated,
+    clippy::undocumented_unsafe_blocks,
+    clippy::as_conversions,
+    clippy::ref_as_ptr,
+    clippy::doc_markdown,
+    clippy::unwrap_used,
+    clippy::unreachable,
+    clippy::impl_trait_in_params,
+    clippy::allow_attributes_without_reason,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::arbitrary_source_item_ordering,
+    clippy::unused_trait_names,
+    clippy::allow_attributes,
+    clippy::redundant_test_prefix,
+)]
+#![forbid(unreachable_pub, missing_docs)]
+
+mod constructor_factory;
+mod fields;
+mod injectable;
+mod ref_constructor_factory;
+
+use proc_macro::TokenStream;
+use syn::{parse::Nothing, parse_macro_input, DeriveInput, ItemImpl};
+
+/// Derive `ConstructorFactory` for a struct by injecting each of its fields.
+///
+/// Every field becomes an element of `Dependencies` in declaration order. The way a field is
+/// created can be changed with the `inject` attribute:
+///
+/// - `#[inject(clone)]`: inject an immutable reference to the dependency and clone it.
+/// - `#[inject(default)]`: don't inject the field and use [`Default::default`] instead.
+/// - `#[inject(skip = expr)]`: don't inject the field and use the specified expression instead.
+///
+/// ```rust
+/// use rustyinject::{DependencyContainer, injector::{factories::ConstructorFactory, Injector}};
+///
+/// #[derive(Clone)]
+/// struct Config;
+///
+/// #[derive(ConstructorFactory)]
+/// struct Service(#[inject(clone)] Config);
+///
+/// #[derive(ConstructorFactory)]
+/// struct App {
+///     service: Service,
+///     #[inject(default)]
+///     requests_count: usize,
+///     #[inject(skip = String::from("app"))]
+///     name: String,
+/// }
+///
+/// let container = DependencyContainer::default()
+///     .with_singleton(Config)
+///     .with_constructor_factory::<Service>()
+///     .with_constructor_factory::<App>();
+///
+/// let _app: App = (&container).inject();
+/// ```
+#[proc_macro_derive(ConstructorFactory, attributes(inject))]
+pub fn derive_constructor_factory(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    constructor_factory::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `RefConstructorFactory<'a>` for a struct with a lifetime parameter by injecting each of
+/// its fields.
+///
+/// The first lifetime parameter of the struct is used as the lifetime of the container reference.
+/// Fields of type `&'a T` are injected as immutable references, so `&'a dyn Trait` fields are
+/// resolved from bindings of the trait or from `Box<dyn Trait>` (and other smart pointer)
+/// singletons. The `inject` attribute works the same way as in
+/// [`ConstructorFactory`](derive@ConstructorFactory).
+///
+/// ```rust
+/// use rustyinject::{DependencyContainer, injector::{factories::RefConstructorFactory, Injector}};
+///
+/// trait Repository {}
+///
+/// struct InMemoryRepository;
+///
+/// impl Repository for InMemoryRepository {}
+///
+/// struct Config;
+///
+/// #[derive(RefConstructorFactory)]
+/// struct App<'a> {
+///     config: &'a Config,
+///     repository: &'a dyn Repository,
+/// }
+///
+/// let container = DependencyContainer::default()
+///     .with_singleton(Config)
+///     .with_binding::<dyn Repository>(Box::new(InMemoryRepository))
+///     .with_ref_constructor_factory::<App>();
+///
+/// let _app: App = (&container).inject();
+/// ```
+#[proc_macro_derive(RefConstructorFactory, attributes(inject))]
+pub fn derive_ref_constructor_factory(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    ref_constructor_factory::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implement a factory trait for a type from the `new` function of its `impl` block.
+///
+/// Each parameter of `new` becomes an element of `Dependencies` in declaration order and `build`
+/// forwards them to `new`. `ConstructorFactory` is implemented if the type has no lifetime
+/// parameters, otherwise `RefConstructorFactory<'a>` is implemented for the first lifetime
+/// parameter `'a`. References without a lifetime and anonymous `'_` lifetimes in the parameters
+/// are replaced with the lifetime of the container reference. Lifetimes elided in paths, like in
+/// `Provider<Report>`, aren't visible to the macro, so they have to be written as `'_`.
+///
+/// `new` must not take `self` and must not have generic parameters.
+///
+/// ```rust
+/// use rustyinject::{DependencyContainer, injector::{factories::injectable, Injector}};
+///
+/// struct Database;
+/// #[derive(Clone)]
+/// struct Cache;
+///
+/// struct App {
+///     cache: Cache,
+/// }
+///
+/// #[injectable]
+/// impl App {
+///     fn new(_db: &Database, cache: Cache) -> Self {
+///         Self { cache }
+///     }
+/// }
+///
+/// let container = DependencyContainer::default()
+///     .with_singleton(Database)
+///     .with_singleton(Cache)
+///     .with_constructor_factory::<App>();
+///
+/// let _app: App = (&container).inject();
+/// ```
+///
+/// A constructor taking `self` is rejected:
+///
+/// ```rust,compile_fail
+/// use rustyinject::injector::factories::injectable;
+///
+/// struct App;
+///
+/// #[injectable]
+/// impl App {
+///     fn new(self) -> Self {
+///         self
+///     }
+/// }
+/// ```
+///
+/// So is a generic constructor:
+///
+/// ```rust,compile_fail
+/// use rustyinject::injector::factories::injectable;
+///
+/// struct App;
+///
+/// #[injectable]
+/// impl App {
+///     fn new<T>(_value: T) -> Self {
+///         Self
+///     }
+/// }
+/// ```
+///
+/// An `impl` block without a `new` function:
+///
+/// ```rust,compile_fail
+/// use rustyinject::injector::factories::injectable;
+///
+/// struct App;
+///
+/// #[injectable]
+/// impl App {
+///     fn create() -> Self {
+///         Self
+///     }
+/// }
+/// ```
+///
+/// And a trait `impl` block:
+///
+/// ```rust,compile_fail
+/// use rustyinject::injector::factories::injectable;
+///
+/// struct App;
+///
+/// #[injectable]
+/// impl Default for App {
+///     fn default() -> Self {
+///         Self
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn injectable(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(attribute as Nothing);
+    let item = parse_macro_input!(item as ItemImpl);
+
+    injectable::expand(&item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
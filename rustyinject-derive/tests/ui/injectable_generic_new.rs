@@ -0,0 +1,12 @@
+use rustyinject::injector::factories::injectable;
+
+struct App;
+
+#[injectable]
+impl App {
+    fn new<T>(_value: T) -> Self {
+        Self
+    }
+}
+
+fn main() {}
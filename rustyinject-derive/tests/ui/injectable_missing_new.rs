@@ -0,0 +1,12 @@
+use rustyinject::injector::factories::injectable;
+
+struct App;
+
+#[injectable]
+impl App {
+    fn create() -> Self {
+        Self
+    }
+}
+
+fn main() {}
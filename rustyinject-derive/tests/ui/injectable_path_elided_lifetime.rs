@@ -0,0 +1,14 @@
+use rustyinject::injector::{factories::injectable, wrappers::Provider};
+
+struct Report;
+
+struct App;
+
+#[injectable]
+impl App {
+    fn new(_reports: Provider<Report>) -> Self {
+        Self
+    }
+}
+
+fn main() {}
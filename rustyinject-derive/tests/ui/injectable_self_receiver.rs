@@ -0,0 +1,12 @@
+use rustyinject::injector::factories::injectable;
+
+struct App;
+
+#[injectable]
+impl App {
+    fn new(self) -> Self {
+        self
+    }
+}
+
+fn main() {}
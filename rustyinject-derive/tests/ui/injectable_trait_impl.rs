@@ -0,0 +1,12 @@
+use rustyinject::injector::factories::injectable;
+
+struct App;
+
+#[injectable]
+impl Default for App {
+    fn default() -> Self {
+        Self
+    }
+}
+
+fn main() {}
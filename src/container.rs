@@ -1,14 +1,28 @@
+#[cfg(feature = "async")]
+use crate::injector::containers::{TokioMutexContainer, TokioRwLockContainer};
 use crate::{
-    deps_list::{DepsList, DepsListGetMut, DepsListGetRef, DepsListRemove},
+    deps_list::{DepsList, DepsListGetMut, DepsListGetRef, DepsListRemove, DepsListSplitMut},
     injector::{
         containers::{
-            ConstructorFactoryContainer, FactoryContainer, RefConstructorFactoryContainer,
-            RefFactoryContainer, SingletonContainer,
+            AbsentContainer, AsyncConstructorFactoryContainer, AsyncFactoryContainer,
+            BindingContainer, CollectMany, ConstructorFactoryContainer, DefaultContainer,
+            FactoryContainer, FnFactoryContainer, LazySingletonContainer, MultiContainer,
+            MutexContainer, NamedContainer, NamedFactoryContainer, NamedSingletonContainer,
+            PoolContainer, RefConstructorFactoryContainer, RefFactoryContainer,
+            RefFnFactoryContainer, RwLockContainer, ScopeCache, ScopedFactoryContainer,
+            SingletonContainer, SyncLazySingletonContainer, TryConstructorFactoryContainer,
+            TryFactoryContainer,
+        },
+        factories::{
+            AsyncConstructorFactory, AsyncFactory, ConstructorFactory, Factory, FnFactory,
+            LifetimeFamily, RefFactory, RefFn, TryConstructorFactory, TryFactory,
         },
-        factories::{ConstructorFactory, Factory, RefFactory},
     },
+    module::Module,
 };
-use core::{convert::Infallible, marker::PhantomData};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{cell::OnceCell, convert::Infallible, marker::PhantomData};
+use std::sync::{Mutex, OnceLock, RwLock};
 
 /// Current scope index.
 pub struct CurrentScope(Infallible);
@@ -53,6 +67,239 @@ where
         }
     }
 
+    /// Declare that a dependency isn't registered, so [`Option`] of it or of an immutable
+    /// reference to it is injected as [`None`].
+    pub fn with_absent<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<AbsentContainer<T>>> {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(AbsentContainer(PhantomData)),
+        }
+    }
+
+    /// Add a dependency that is created with [`Default::default`] on each injection.
+    ///
+    /// To inject the default value only when a dependency isn't registered, declare it absent with
+    /// [`with_absent`](Self::with_absent) and inject
+    /// [`Defaulted`](crate::injector::wrappers::Defaulted) of it instead.
+    pub fn with_default<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<DefaultContainer<T>>>
+    where
+        T: Default,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(DefaultContainer(PhantomData)),
+        }
+    }
+
+    /// Add a concrete instance of a dependency (singleton) qualified with the `Tag`, so it's
+    /// injected only as [`Named`](crate::injector::wrappers::Named) or
+    /// [`NamedValue`](crate::injector::wrappers::NamedValue) with the same tag.
+    pub fn with_named_singleton<Tag, T>(
+        self,
+        singleton: T,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<NamedSingletonContainer<Tag, T>>> {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(NamedContainer(SingletonContainer(singleton), PhantomData)),
+        }
+    }
+
+    /// Add a factory-based dependency qualified with the `Tag`, so its result is injected only as
+    /// [`NamedValue`](crate::injector::wrappers::NamedValue) with the same tag.
+    pub fn with_named_factory<Tag, F>(
+        self,
+        factory: F,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<NamedFactoryContainer<Tag, F>>>
+    where
+        F: Factory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(NamedContainer(
+                FactoryContainer(factory, PhantomData),
+                PhantomData,
+            )),
+        }
+    }
+
+    /// Add one of many boxed dependencies of the same type that are injected all at once as
+    /// [`Many`](crate::injector::wrappers::Many).
+    pub fn with_multi<T>(
+        self,
+        dependency: Box<T>,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<MultiContainer<T>>>
+    where
+        T: ?Sized,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(MultiContainer(dependency)),
+        }
+    }
+
+    /// Add a singleton behind a [`Mutex`](std::sync::Mutex) that is injected as a guard of the
+    /// locked mutex.
+    pub fn with_mutex_singleton<T>(
+        self,
+        singleton: T,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<MutexContainer<T>>> {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(MutexContainer(Mutex::new(singleton))),
+        }
+    }
+
+    /// Add a singleton behind a [`RwLock`](std::sync::RwLock) that is injected as a guard of the
+    /// lock locked for reading or writing.
+    pub fn with_rwlock_singleton<T>(
+        self,
+        singleton: T,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<RwLockContainer<T>>> {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(RwLockContainer(RwLock::new(singleton))),
+        }
+    }
+
+    /// Same as [`with_mutex_singleton`](Self::with_mutex_singleton) but the singleton is behind a
+    /// tokio [`Mutex`](tokio::sync::Mutex). The mutex itself is injected by reference, and its
+    /// guard is injected with [`AsyncInjector`](crate::injector::AsyncInjector) awaiting the lock.
+    #[cfg(feature = "async")]
+    pub fn with_tokio_mutex_singleton<T>(
+        self,
+        singleton: T,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<TokioMutexContainer<T>>> {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(TokioMutexContainer(tokio::sync::Mutex::new(singleton))),
+        }
+    }
+
+    /// Same as [`with_rwlock_singleton`](Self::with_rwlock_singleton) but the singleton is behind
+    /// a tokio [`RwLock`](tokio::sync::RwLock). The lock itself is injected by reference, and
+    /// its read and write guards are injected with
+    /// [`AsyncInjector`](crate::injector::AsyncInjector) awaiting the lock.
+    #[cfg(feature = "async")]
+    pub fn with_tokio_rwlock_singleton<T>(
+        self,
+        singleton: T,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<TokioRwLockContainer<T>>> {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(TokioRwLockContainer(tokio::sync::RwLock::new(singleton))),
+        }
+    }
+
+    /// Add a struct that builds from a constructor(like a `new` method) that can fail.
+    pub fn with_try_constructor_factory<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<TryConstructorFactoryContainer<T>>>
+    where
+        T: TryConstructorFactory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(TryConstructorFactoryContainer(PhantomData)),
+        }
+    }
+
+    /// Add a struct that builds from an asynchronous constructor(like an `async fn new` method).
+    pub fn with_async_constructor_factory<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<AsyncConstructorFactoryContainer<T>>>
+    where
+        T: AsyncConstructorFactory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(AsyncConstructorFactoryContainer(PhantomData)),
+        }
+    }
+
+    /// Add a singleton that is built from a constructor(like a `new` method) on the first
+    /// injection of an immutable reference to it.
+    pub fn with_lazy_singleton<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<LazySingletonContainer<T>>>
+    where
+        T: ConstructorFactory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(LazySingletonContainer(OnceCell::new())),
+        }
+    }
+
+    /// Same as [`with_lazy_singleton`](Self::with_lazy_singleton) but the singleton is stored in
+    /// a thread-safe cell, so the container can be shared between threads.
+    pub fn with_sync_lazy_singleton<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<SyncLazySingletonContainer<T>>>
+    where
+        T: ConstructorFactory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(SyncLazySingletonContainer(OnceLock::new())),
+        }
+    }
+
+    /// Bind the `Interface` (usually a trait object) to a boxed implementation, so it's injected
+    /// as `&Interface`.
+    pub fn with_binding<Interface>(
+        self,
+        implementation: Box<Interface>,
+    ) -> DependencyContainer<
+        Parent,
+        Scope::PrependedWith<BindingContainer<Interface, Box<Interface>>>,
+    >
+    where
+        Interface: ?Sized,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(BindingContainer(implementation, PhantomData)),
+        }
+    }
+
+    /// Bind the `Interface` (usually a trait object) to an implementation stored in an [`Arc`],
+    /// so it's injected as `&Interface` or as a clone of the [`Arc`].
+    pub fn with_arc_binding<Interface>(
+        self,
+        implementation: Arc<Interface>,
+    ) -> DependencyContainer<
+        Parent,
+        Scope::PrependedWith<BindingContainer<Interface, Arc<Interface>>>,
+    >
+    where
+        Interface: ?Sized,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(BindingContainer(implementation, PhantomData)),
+        }
+    }
+
     /// Add a factory-based dependency to the container.
     pub fn with_factory<F>(
         self,
@@ -83,6 +330,89 @@ where
         }
     }
 
+    /// Add a pool of objects built by the factory that are injected as
+    /// [`Pooled`](crate::injector::wrappers::Pooled) handles. New objects are built when there
+    /// are no idle ones, so the number of live objects isn't limited. At most `max_idle` of them
+    /// are kept in the pool when returned.
+    pub fn with_pool<F>(
+        self,
+        factory: F,
+        max_idle: usize,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<PoolContainer<F, F::Result>>>
+    where
+        F: Factory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(PoolContainer::new(factory, max_idle)),
+        }
+    }
+
+    /// Add a factory-based dependency whose factory can fail.
+    pub fn with_try_factory<F>(
+        self,
+        factory: F,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<TryFactoryContainer<F, F::Result>>>
+    where
+        F: TryFactory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(TryFactoryContainer(factory, PhantomData)),
+        }
+    }
+
+    /// Add a factory-based dependency whose factory builds it asynchronously.
+    pub fn with_async_factory<F>(
+        self,
+        factory: F,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<AsyncFactoryContainer<F, F::Result>>>
+    where
+        F: AsyncFactory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self
+                .scope
+                .prepend(AsyncFactoryContainer(factory, PhantomData)),
+        }
+    }
+
+    /// Add a factory-based dependency built by a closure or a function pointer. Dependencies
+    /// are named with a [`LifetimeFamily`] so that the closure accepts them borrowed for any
+    /// lifetime, e.g. `with_fn_factory::<Deps![Borrowed<Database>], _, _>(|deps_pat![db]| ...)`.
+    pub fn with_fn_factory<Dependencies, F, T>(
+        self,
+        factory: F,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<FnFactoryContainer<F, Dependencies, T>>>
+    where
+        F: for<'a> Fn(Dependencies::Of<'a>) -> T,
+        Dependencies: LifetimeFamily,
+    {
+        self.with_factory(FnFactory::new(factory))
+    }
+
+    /// Add a factory-based dependency built by a closure or a function pointer that consumes
+    /// references to its dependencies. Both dependencies and the result are named with a
+    /// [`LifetimeFamily`], e.g.
+    /// `with_ref_fn_factory::<Deps![Borrowed<Database>], _, App<'static>>(|deps_pat![db]| App(db))`.
+    pub fn with_ref_fn_factory<'a, Dependencies, F, T>(
+        self,
+        factory: F,
+    ) -> DependencyContainer<
+        Parent,
+        Scope::PrependedWith<RefFnFactoryContainer<'a, F, Dependencies, T>>,
+    >
+    where
+        F: for<'b> RefFn<'b, Dependencies, T>,
+        Dependencies: LifetimeFamily,
+        T: LifetimeFamily,
+    {
+        self.with_ref_factory(FnFactory::new(factory))
+    }
+
     /// Add a struct that builds from a constructor(like a `new` method).
     pub fn with_constructor_factory<T>(
         self,
@@ -96,8 +426,43 @@ where
         }
     }
 
+    /// Add a struct that builds from a constructor(like a `new` method) once on the first
+    /// injection and then is injected as an immutable reference or by cloning. Registers the same
+    /// container as [`with_lazy_singleton`](Self::with_lazy_singleton).
+    pub fn with_cached_constructor_factory<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<LazySingletonContainer<T>>>
+    where
+        T: ConstructorFactory,
+    {
+        self.with_lazy_singleton()
+    }
+
+    /// Add a struct that builds from a constructor(like a `new` method) at most once per child
+    /// scope created with [`scope`](Self::scope). The instance is injected as an immutable
+    /// reference from the child scope and dropped together with it.
+    pub fn with_scoped_factory<T>(
+        self,
+    ) -> DependencyContainer<Parent, Scope::PrependedWith<ScopedFactoryContainer<T>>>
+    where
+        T: ConstructorFactory,
+    {
+        DependencyContainer {
+            parent: self.parent,
+            scope: self.scope.prepend(ScopedFactoryContainer(PhantomData)),
+        }
+    }
+
+    /// Add all registrations of the module to the container.
+    pub fn with_module<M>(self, module: M) -> M::Output
+    where
+        M: Module<Parent, Scope>,
+    {
+        module.register(self)
+    }
+
     /// Add a struct that builds from a constructor(like a `new` method) and consumes all
-    /// references that passed
+    /// references that passed.
     pub fn with_ref_constructor_factory<T>(
         self,
     ) -> DependencyContainer<Parent, Scope::PrependedWith<RefConstructorFactoryContainer<T>>> {
@@ -110,6 +475,52 @@ where
     }
 }
 
+impl<Parent, Scope> DependencyContainer<Parent, Scope>
+where
+    Scope: ScopeCache,
+{
+    /// Create a child scope of the container that caches instances of scoped factories registered
+    /// in the current scope of the container.
+    pub fn scope(&self) -> DependencyContainer<&Self, Scope::Cache> {
+        DependencyContainer {
+            parent: self,
+            scope: Scope::Cache::default(),
+        }
+    }
+}
+
+impl<Parent, Scope> CollectMany for DependencyContainer<Parent, Scope>
+where
+    Parent: CollectMany,
+    Scope: CollectMany,
+{
+    fn collect_many<'a, T>(&'a self, many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+        self.parent.collect_many(many);
+        self.scope.collect_many(many);
+    }
+}
+
+impl<'a, Parent, Scope> DepsListSplitMut for &'a mut DependencyContainer<Parent, Scope>
+where
+    &'a mut Parent: DepsListSplitMut,
+    &'a mut Scope: DepsListSplitMut,
+{
+    type Split = DependencyContainer<
+        <&'a mut Parent as DepsListSplitMut>::Split,
+        <&'a mut Scope as DepsListSplitMut>::Split,
+    >;
+
+    fn split_mut(self) -> Self::Split {
+        DependencyContainer {
+            parent: self.parent.split_mut(),
+            scope: self.scope.split_mut(),
+        }
+    }
+}
+
 impl<Parent, Scope, T, Idx> DepsListRemove<T, (CurrentScope, Idx)>
     for DependencyContainer<Parent, Scope>
 where
@@ -188,3 +599,303 @@ where
         self.parent.get_mut()
     }
 }
+
+/// Declare a type alias of a [`DependencyContainer`] together with a function that builds it, so
+/// the type of the container doesn't have to be kept in sync with its registrations by hand.
+///
+/// Registrations are listed in the order they are added to the container:
+///
+/// - `singleton name: Type = value` adds a singleton with [`with_singleton`](DependencyContainer::with_singleton).
+/// - `factory name: Type = value` or `factory Type` (for unit structs) adds a factory with
+///   [`with_factory`](DependencyContainer::with_factory).
+/// - `binding name: dyn Trait = value` binds a trait to a boxed implementation with
+///   [`with_binding`](DependencyContainer::with_binding).
+/// - `arc_binding name: dyn Trait = value` binds a trait to an implementation stored in an `Arc`
+///   with [`with_arc_binding`](DependencyContainer::with_arc_binding).
+/// - `ref_factory<'a> name: Type = value` adds a factory with
+///   [`with_ref_factory`](DependencyContainer::with_ref_factory) whose result borrows from the
+///   container for `'a`.
+/// - `constructor Type` adds a constructor factory with
+///   [`with_constructor_factory`](DependencyContainer::with_constructor_factory).
+/// - `ref_constructor Type` adds a constructor factory with
+///   [`with_ref_constructor_factory`](DependencyContainer::with_ref_constructor_factory).
+///
+/// Lifetimes of the type alias can be used by registrations and the function can take parameters
+/// that are used by values of registrations.
+///
+/// ```rust
+/// use rustyinject::{container, injector::{factories::RefConstructorFactory, Injector}};
+///
+/// struct Config(u32);
+/// struct Database;
+///
+/// struct App<'a>(&'a Config, &'a Database);
+///
+/// impl<'a> RefConstructorFactory<'a> for App<'a> {
+///     type Dependencies = (&'a Config, &'a Database);
+///
+///     fn build((config, db): Self::Dependencies) -> Self {
+///         Self(config, db)
+///     }
+/// }
+///
+/// container! {
+///     /// Container of the application.
+///     type Container<'a>;
+///
+///     /// Build the container of the application.
+///     fn new_container(port: u32) {
+///         singleton config: Config = Config(port),
+///         singleton db: Database = Database,
+///         ref_constructor App<'a>,
+///     }
+/// }
+///
+/// let container: Container = new_container(8080);
+/// let _app: App = (&container).inject();
+/// ```
+#[macro_export]
+macro_rules! container {
+    (
+        $(#[$alias_meta:meta])*
+        $alias_vis:vis type $alias:ident $(<$($lifetime:lifetime),+ $(,)?>)?;
+
+        $(#[$builder_meta:meta])*
+        $builder_vis:vis fn $builder:ident($($argument:ident: $argument_type:ty),* $(,)?) {
+            $($registrations:tt)*
+        }
+    ) => {
+        $(#[$alias_meta])*
+        $alias_vis type $alias $(<$($lifetime),+>)? = $crate::DependencyContainer<
+            (),
+            $crate::container!(@scope [()] $($registrations)*),
+        >;
+
+        $(#[$builder_meta])*
+        $builder_vis fn $builder $(<$($lifetime),+>)? (
+            $($argument: $argument_type),*
+        ) -> $alias $(<$($lifetime),+>)? {
+            $crate::container!(@chain [$crate::DependencyContainer::default()] $($registrations)*)
+        }
+    };
+
+    (@scope [$scope:ty] $(,)?) => { $scope };
+    (@scope [$scope:ty] singleton $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @scope [($crate::injector::containers::SingletonContainer<$type>, $scope)]
+            $($($rest)*)?
+        )
+    };
+    (@scope [$scope:ty] factory $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @scope [(
+                $crate::injector::containers::FactoryContainer<
+                    $type,
+                    <$type as $crate::injector::factories::Factory>::Result,
+                >,
+                $scope,
+            )]
+            $($($rest)*)?
+        )
+    };
+    (@scope [$scope:ty] factory $type:ident $(, $($rest:tt)*)?) => {
+        $crate::container!(@scope [$scope] factory factory: $type = $type $(, $($rest)*)?)
+    };
+    (
+        @scope [$scope:ty]
+        ref_factory<$lifetime:lifetime> $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::container!(
+            @scope [(
+                $crate::injector::containers::RefFactoryContainer<
+                    $type,
+                    <$type as $crate::injector::factories::RefFactory>::Result<$lifetime>,
+                >,
+                $scope,
+            )]
+            $($($rest)*)?
+        )
+    };
+    (@scope [$scope:ty] binding $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @scope [(
+                $crate::injector::containers::BindingContainer<
+                    $type,
+                    $crate::__private::Box<$type>,
+                >,
+                $scope,
+            )]
+            $($($rest)*)?
+        )
+    };
+    (@scope [$scope:ty] arc_binding $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @scope [(
+                $crate::injector::containers::BindingContainer<
+                    $type,
+                    $crate::__private::Arc<$type>,
+                >,
+                $scope,
+            )]
+            $($($rest)*)?
+        )
+    };
+    (@scope [$scope:ty] constructor $type:ty $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @scope [($crate::injector::containers::ConstructorFactoryContainer<$type>, $scope)]
+            $($($rest)*)?
+        )
+    };
+    (@scope [$scope:ty] ref_constructor $type:ty $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @scope [($crate::injector::containers::RefConstructorFactoryContainer<$type>, $scope)]
+            $($($rest)*)?
+        )
+    };
+
+    (@chain [$($chain:tt)*] $(,)?) => { $($chain)* };
+    (@chain [$($chain:tt)*] singleton $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @chain [$($chain)*.with_singleton({
+                let $name: $type = $value;
+                $name
+            })]
+            $($($rest)*)?
+        )
+    };
+    (@chain [$($chain:tt)*] factory $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @chain [$($chain)*.with_factory({
+                let $name: $type = $value;
+                $name
+            })]
+            $($($rest)*)?
+        )
+    };
+    (@chain [$($chain:tt)*] factory $type:ident $(, $($rest:tt)*)?) => {
+        $crate::container!(@chain [$($chain)*] factory factory: $type = $type $(, $($rest)*)?)
+    };
+    (
+        @chain [$($chain:tt)*]
+        ref_factory<$lifetime:lifetime> $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::container!(
+            @chain [$($chain)*.with_ref_factory::<$lifetime, $type>({
+                let $name: $type = $value;
+                $name
+            })]
+            $($($rest)*)?
+        )
+    };
+    (@chain [$($chain:tt)*] binding $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @chain [$($chain)*.with_binding::<$type>({
+                let $name: $crate::__private::Box<$type> = $value;
+                $name
+            })]
+            $($($rest)*)?
+        )
+    };
+    (@chain [$($chain:tt)*] arc_binding $name:ident: $type:ty = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @chain [$($chain)*.with_arc_binding::<$type>({
+                let $name: $crate::__private::Arc<$type> = $value;
+                $name
+            })]
+            $($($rest)*)?
+        )
+    };
+    (@chain [$($chain:tt)*] constructor $type:ty $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @chain [$($chain)*.with_constructor_factory::<$type>()]
+            $($($rest)*)?
+        )
+    };
+    (@chain [$($chain:tt)*] ref_constructor $type:ty $(, $($rest:tt)*)?) => {
+        $crate::container!(
+            @chain [$($chain)*.with_ref_constructor_factory::<$type>()]
+            $($($rest)*)?
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::injector::{
+        factories::{ConstructorFactory, Factory, RefFactory},
+        Injector,
+    };
+
+    #[derive(Clone)]
+    struct Database;
+    struct Cache;
+
+    struct App;
+
+    impl ConstructorFactory for App {
+        type Dependencies<'a> = (&'a Database, &'a Cache);
+
+        fn build(_dependencies: Self::Dependencies<'_>) -> Self {
+            Self
+        }
+    }
+
+    struct Pool(Database);
+
+    struct PoolFactory;
+
+    impl Factory for PoolFactory {
+        type Result = Pool;
+        type Dependencies<'a> = (&'a Database,);
+
+        fn build(&self, (db,): Self::Dependencies<'_>) -> Self::Result {
+            Pool(db.clone())
+        }
+    }
+
+    struct CacheRef<'a>(&'a Cache);
+
+    struct CacheRefFactory;
+
+    impl RefFactory for CacheRefFactory {
+        type Result<'a> = CacheRef<'a>;
+        type Dependencies<'a> = (&'a Cache,);
+
+        fn build<'a>(&self, (cache,): Self::Dependencies<'a>) -> Self::Result<'a> {
+            CacheRef(cache)
+        }
+    }
+
+    trait Repository {}
+
+    impl Repository for Database {}
+
+    trait Metrics {}
+
+    impl Metrics for Cache {}
+
+    crate::container! {
+        type Container<'a>;
+
+        fn new_container(cache: Cache) {
+            singleton db: Database = Database,
+            singleton cache: Cache = cache,
+            factory PoolFactory,
+            ref_factory<'a> cache_ref_factory: CacheRefFactory = CacheRefFactory,
+            constructor App,
+            binding repository: dyn Repository = Box::new(Database),
+            arc_binding metrics: dyn Metrics = alloc::sync::Arc::new(Cache),
+        }
+    }
+
+    #[test]
+    fn test_container_macro() {
+        let container: Container = new_container(Cache);
+
+        let _app: App = (&container).inject();
+        let Pool(_db) = (&container).inject();
+        let CacheRef(_cache) = (&container).inject();
+        let _repository: &dyn Repository = (&container).inject();
+        let _metrics: &dyn Metrics = (&container).inject();
+    }
+}
@@ -8,7 +8,7 @@ use core::{
 /// Heterogeneously-typed list for storing dependencies.
 pub trait DepsList: Sized {
     /// New list with specific element prepended.
-    type PrependedWith<T>;
+    type PrependedWith<T>: DepsList;
 
     /// Prepend element to a list.
     fn prepend<T>(self, value: T) -> Self::PrependedWith<T>;
@@ -30,6 +30,9 @@ impl<Head, Tail> DepsList for (Head, Tail) {
     }
 }
 
+/// List `L` with element `T` prepended.
+pub type Prepended<L, T> = <L as DepsList>::PrependedWith<T>;
+
 /// Last index of the list.
 pub struct Last(Infallible);
 /// Next index of the list.
@@ -159,3 +162,102 @@ where
         (removed, (self.0, tail_remainder))
     }
 }
+
+/// A mutable reference to a heterogeneously-typed list that can be split into a list of mutable
+/// references to its elements.
+pub trait DepsListSplitMut {
+    /// List of mutable references to elements.
+    type Split;
+
+    /// Split the list into a list of mutable references to its elements.
+    fn split_mut(self) -> Self::Split;
+}
+
+impl DepsListSplitMut for &mut () {
+    type Split = ();
+
+    fn split_mut(self) -> Self::Split {}
+}
+
+impl<'a, Head, Tail> DepsListSplitMut for &'a mut (Head, Tail)
+where
+    &'a mut Tail: DepsListSplitMut,
+{
+    type Split = (&'a mut Head, <&'a mut Tail as DepsListSplitMut>::Split);
+
+    fn split_mut(self) -> Self::Split {
+        (&mut self.0, self.1.split_mut())
+    }
+}
+
+// A parent container held by a mutable reference.
+#[allow(clippy::mut_mut)]
+impl<'a, D> DepsListSplitMut for &'a mut &mut D
+where
+    &'a mut D: DepsListSplitMut,
+{
+    type Split = <&'a mut D as DepsListSplitMut>::Split;
+
+    fn split_mut(self) -> Self::Split {
+        (**self).split_mut()
+    }
+}
+
+impl<'a, D> DepsListSplitMut for &'a mut Box<D>
+where
+    &'a mut D: DepsListSplitMut,
+{
+    type Split = <&'a mut D as DepsListSplitMut>::Split;
+
+    fn split_mut(self) -> Self::Split {
+        self.deref_mut().split_mut()
+    }
+}
+
+/// A shared list can't be split into mutable references, so it's split into an empty list.
+impl<D> DepsListSplitMut for &mut &D {
+    type Split = ();
+
+    fn split_mut(self) -> Self::Split {}
+}
+
+/// A shared list can't be split into mutable references, so it's split into an empty list.
+impl<D> DepsListSplitMut for &mut Rc<D> {
+    type Split = ();
+
+    fn split_mut(self) -> Self::Split {}
+}
+
+/// A shared list can't be split into mutable references, so it's split into an empty list.
+impl<D> DepsListSplitMut for &mut Arc<D> {
+    type Split = ();
+
+    fn split_mut(self) -> Self::Split {}
+}
+
+/// Build a [`DepsList`] type from the listed types.
+///
+/// `Deps![A, &'a B, C]` expands to `(A, (&'a B, (C, ())))`.
+#[macro_export]
+macro_rules! Deps {
+    () => { () };
+    ($head:ty $(, $tail:ty)* $(,)?) => { ($head, $crate::Deps![$($tail),*]) };
+}
+
+/// Build a [`DepsList`] value from the listed expressions.
+///
+/// `deps![a, b, c]` expands to `(a, (b, (c, ())))`.
+#[macro_export]
+macro_rules! deps {
+    () => { () };
+    ($head:expr $(, $tail:expr)* $(,)?) => { ($head, $crate::deps![$($tail),*]) };
+}
+
+/// Build a pattern destructuring a [`DepsList`] into the listed patterns.
+///
+/// `let deps_pat![a, b, c] = dependencies;` expands to `let (a, (b, (c, ()))) = dependencies;`.
+#[macro_export]
+macro_rules! deps_pat {
+    () => { () };
+    ($head:pat $(, $tail:pat)* $(,)?) => { ($head, $crate::deps_pat![$($tail),*]) };
+}
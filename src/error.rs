@@ -0,0 +1,101 @@
+use alloc::{vec, vec::Vec};
+use core::{
+    any::type_name,
+    fmt::{self, Display, Formatter},
+};
+
+/// An error of fallible dependency injection.
+///
+/// Wraps the root cause together with the path of types that were being built when it occurred,
+/// from the requested type down to the one whose factory failed.
+#[derive(Debug)]
+pub struct ResolveError<E> {
+    path: Vec<&'static str>,
+    source: E,
+}
+
+impl<E> ResolveError<E> {
+    /// Creates an error caused by a failed build of `T`.
+    pub fn new<T: ?Sized>(source: E) -> Self {
+        Self {
+            path: vec![type_name::<T>()],
+            source,
+        }
+    }
+
+    /// Records that the error occurred while building dependencies of `T`.
+    #[must_use]
+    pub fn within<T: ?Sized>(mut self) -> Self {
+        self.path.insert(0, type_name::<T>());
+        self
+    }
+
+    /// Names of types being built, from the requested type down to the one whose factory failed.
+    #[must_use]
+    pub fn path(&self) -> &[&'static str] {
+        &self.path
+    }
+
+    /// Returns a reference to the root cause.
+    #[must_use]
+    pub const fn source_ref(&self) -> &E {
+        &self.source
+    }
+
+    /// Returns the root cause.
+    #[must_use]
+    pub fn into_source(self) -> E {
+        self.source
+    }
+}
+
+impl<E: Display> Display for ResolveError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("failed to resolve ")?;
+        for (i, name) in self.path.iter().enumerate() {
+            if i != 0 {
+                f.write_str(" -> ")?;
+            }
+            f.write_str(name)?;
+        }
+        write!(f, ": {}", self.source)
+    }
+}
+
+impl<E> core::error::Error for ResolveError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use core::num::ParseIntError;
+
+    #[test]
+    fn test_path() {
+        struct App;
+        struct DbPool;
+
+        let source = "port".parse::<u16>().unwrap_err();
+        let error = ResolveError::new::<DbPool>(source.clone()).within::<App>();
+
+        assert_eq!(error.path(), [type_name::<App>(), type_name::<DbPool>()]);
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "failed to resolve {} -> {}: {source}",
+                type_name::<App>(),
+                type_name::<DbPool>()
+            )
+        );
+        assert!(core::error::Error::source(&error)
+            .is_some_and(|cause| cause.downcast_ref::<ParseIntError>().is_some()));
+        assert_eq!(error.into_source(), source);
+    }
+}
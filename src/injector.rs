@@ -1,35 +1,107 @@
 //! Injector trait, containers and strategies needed for dependency injection.
 
+mod async_factory;
+mod binding;
 mod constructor_factory;
+mod default;
 mod factory;
+mod flat_tuple;
+mod lazy_singleton;
+mod lock;
+mod multi;
+mod named;
+mod optional;
+mod pool;
+mod provider;
+mod scoped;
 mod singleton;
 mod singleton_cloned;
+mod singleton_deref;
+mod try_factory;
+
+pub use singleton::{SingletonList, SingletonRefList};
+pub use singleton_deref::SingletonDerefList;
 
 pub mod strategies {
     //! Strategies of dependency injection.
+    pub use super::async_factory::{
+        AsyncConstructorFactoryStrategy, AsyncFactoryStrategy, SynchronousStrategy,
+    };
+    pub use super::binding::{ArcBindingStrategy, BindingStrategy};
     pub use super::constructor_factory::{
         ConstructorFactoryStrategy, RefConstructorFactoryStrategy,
     };
+    pub use super::default::{DefaultStrategy, DefaultedStrategy};
     pub use super::factory::{FactoryStrategy, RefFactoryStrategy};
+    pub use super::flat_tuple::FlatTupleStrategy;
+    pub use super::lazy_singleton::{
+        LazySingletonClonedStrategy, LazySingletonStrategy, SyncLazySingletonClonedStrategy,
+        SyncLazySingletonStrategy,
+    };
+    pub use super::lock::{MutexStrategy, RwLockStrategy};
+    pub use super::multi::ManyStrategy;
+    pub use super::named::{NamedClonedStrategy, NamedFactoryStrategy, NamedStrategy};
+    pub use super::optional::{AbsentRefStrategy, AbsentStrategy, OptionalStrategy};
+    pub use super::pool::PoolStrategy;
+    pub use super::provider::ProviderStrategy;
+    pub use super::scoped::ScopedFactoryStrategy;
     pub use super::singleton::SingletonStrategy;
     pub use super::singleton_cloned::SinglentonClonedStrategy;
+    pub use super::singleton_deref::SingletonDerefStrategy;
+    pub use super::try_factory::{
+        InfallibleStrategy, TryConstructorFactoryStrategy, TryFactoryStrategy,
+    };
 }
 
 pub mod containers {
     //! Containers for storing dependencies.
+    pub use super::async_factory::{AsyncConstructorFactoryContainer, AsyncFactoryContainer};
+    pub use super::binding::BindingContainer;
     pub use super::constructor_factory::{
         ConstructorFactoryContainer, RefConstructorFactoryContainer,
     };
-    pub use super::factory::{FactoryContainer, RefFactoryContainer};
+    pub use super::default::DefaultContainer;
+    pub use super::factory::{
+        FactoryContainer, FnFactoryContainer, RefFactoryContainer, RefFnFactoryContainer,
+    };
+    pub use super::lazy_singleton::{LazySingletonContainer, SyncLazySingletonContainer};
+    pub use super::lock::{MutexContainer, RwLockContainer};
+    #[cfg(feature = "async")]
+    pub use super::lock::{TokioMutexContainer, TokioRwLockContainer};
+    pub use super::multi::{CollectMany, MultiContainer};
+    pub use super::named::{NamedContainer, NamedFactoryContainer, NamedSingletonContainer};
+    pub use super::optional::AbsentContainer;
+    pub use super::pool::PoolContainer;
+    pub use super::scoped::{ScopeCache, ScopedContainer, ScopedFactoryContainer};
     pub use super::singleton::SingletonContainer;
+    pub use super::try_factory::{TryConstructorFactoryContainer, TryFactoryContainer};
+}
+
+pub mod wrappers {
+    //! Wrappers of dependencies that are injected instead of the dependencies themselves.
+    pub use super::default::Defaulted;
+    pub use super::multi::Many;
+    pub use super::named::{Named, NamedValue};
+    pub use super::pool::Pooled;
+    pub use super::provider::Provider;
 }
 
 pub mod factories {
     //! Factories used for creation instances of structs that depend on others.
+    pub use super::async_factory::{AsyncConstructorFactory, AsyncFactory};
     pub use super::constructor_factory::{ConstructorFactory, RefConstructorFactory};
-    pub use super::factory::{Factory, RefFactory};
+    pub use super::factory::{
+        Borrowed, Factory, FnFactory, LifetimeFamily, Owned, RefFactory, RefFn,
+    };
+    pub use super::try_factory::{TryConstructorFactory, TryFactory};
+    #[cfg(feature = "derive")]
+    pub use rustyinject_derive::{injectable, ConstructorFactory, RefConstructorFactory};
 }
 
+use crate::ResolveError;
+use async_factory::join;
+use core::future::{ready, Future};
+
 /// A trait for performing dependency injection.
 /// It serves as a generic interface for implementing dependency injection logic.
 pub trait Injector<T, Infer> {
@@ -57,6 +129,74 @@ impl<C> ListInjector<(), ()> for &C {
     fn inject_list(self) {}
 }
 
+/// A trait for performing dependency injection that can fail.
+pub trait TryInjector<T, E, Infer> {
+    /// Try to inject a dependency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dependency or one of its dependencies can't be built.
+    fn try_inject(self) -> Result<T, ResolveError<E>>;
+}
+
+/// A trait for performing dependency injection of many dependencies at once that can fail.
+pub trait TryListInjector<T, E, Infer> {
+    /// Try to inject a list of dependencies.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of the dependencies that can't be built.
+    fn try_inject_list(self) -> Result<T, ResolveError<E>>;
+}
+
+impl<'a, Head, Tail, HeadInfer, TailInfer, C, E>
+    TryListInjector<(Head, Tail), E, (HeadInfer, TailInfer)> for &'a C
+where
+    &'a C: TryInjector<Head, E, HeadInfer> + TryListInjector<Tail, E, TailInfer>,
+{
+    fn try_inject_list(self) -> Result<(Head, Tail), ResolveError<E>> {
+        Ok((self.try_inject()?, self.try_inject_list()?))
+    }
+}
+
+impl<C, E> TryListInjector<(), E, ()> for &C {
+    fn try_inject_list(self) -> Result<(), ResolveError<E>> {
+        Ok(())
+    }
+}
+
+/// A trait for performing dependency injection asynchronously.
+/// Returned futures are `Send`, so injection can be awaited in spawned tasks.
+pub trait AsyncInjector<T, Infer> {
+    /// Inject a dependency asynchronously.
+    fn inject_async(self) -> impl Future<Output = T> + Send;
+}
+
+/// A trait for performing asynchronous dependency injection of many dependencies at once.
+/// Dependencies of the list are resolved concurrently.
+pub trait AsyncListInjector<T, Infer> {
+    /// Inject a list of dependencies asynchronously.
+    fn inject_list_async(self) -> impl Future<Output = T> + Send;
+}
+
+impl<'a, Head, Tail, HeadInfer, TailInfer, C>
+    AsyncListInjector<(Head, Tail), (HeadInfer, TailInfer)> for &'a C
+where
+    &'a C: AsyncInjector<Head, HeadInfer> + AsyncListInjector<Tail, TailInfer>,
+    Head: Send,
+    Tail: Send,
+{
+    fn inject_list_async(self) -> impl Future<Output = (Head, Tail)> + Send {
+        join(self.inject_async(), self.inject_list_async())
+    }
+}
+
+impl<C> AsyncListInjector<(), ()> for &C {
+    fn inject_list_async(self) -> impl Future<Output = ()> + Send {
+        ready(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::DependencyContainer;
@@ -75,4 +215,34 @@ mod tests {
 
         let _deps: (Database, (&Cache, ())) = (&container).inject_list();
     }
+
+    #[test]
+    fn test_inject_flat_tuple() {
+        use crate::injector::factories::ConstructorFactory;
+
+        #[derive(Clone)]
+        struct Database;
+        struct Cache;
+
+        #[allow(dead_code)]
+        struct App(Database);
+
+        impl ConstructorFactory for App {
+            type Dependencies<'a> = (Database, &'a Cache);
+
+            fn build((db, _cache): Self::Dependencies<'_>) -> Self {
+                Self(db)
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(Database)
+            .with_singleton(Cache)
+            .with_constructor_factory::<App>();
+
+        let _deps: (Database,) = (&container).inject_list();
+        let _deps: (Database, &Cache) = (&container).inject_list();
+        let _deps: (&Database, &Cache, Database, App) = (&container).inject_list();
+        let _app: App = (&container).inject();
+    }
 }
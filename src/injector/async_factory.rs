@@ -0,0 +1,286 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    AsyncInjector, AsyncListInjector, Injector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use core::{
+    convert::Infallible,
+    future::{poll_fn, ready, Future},
+    marker::PhantomData,
+    pin::pin,
+    task::Poll,
+};
+
+/// A trait representing a factory for creating an instance from dependencies asynchronously.
+pub trait AsyncFactory {
+    /// A result of the factory [`build`](AsyncFactory::build) method.
+    type Result;
+    /// Dependencies of the factory.
+    type Dependencies<'a>;
+
+    /// Build result from dependencies.
+    fn build(
+        &self,
+        dependencies: Self::Dependencies<'_>,
+    ) -> impl Future<Output = Self::Result> + Send;
+}
+
+/// A trait representing a struct that builds from an asynchronous constructor.
+pub trait AsyncConstructorFactory: Sized {
+    /// Dependencies of the factory.
+    type Dependencies<'a>;
+
+    /// Creates a new instance from dependencies.
+    fn build(dependencies: Self::Dependencies<'_>) -> impl Future<Output = Self> + Send;
+}
+
+/// A marker struct used to signify that a synchronous strategy is used for asynchronous
+/// dependency injection.
+pub struct SynchronousStrategy<Infer>(PhantomData<Infer>, Infallible);
+/// A marker struct used to signify the asynchronous factory strategy in dependency injection.
+pub struct AsyncFactoryStrategy<F, FactoryInfer>(PhantomData<(F, FactoryInfer)>, Infallible);
+/// A marker struct used to signify the asynchronous constructor factory strategy in dependency
+/// injection.
+pub struct AsyncConstructorFactoryStrategy<ConstructorInfer>(
+    PhantomData<ConstructorInfer>,
+    Infallible,
+);
+
+/// A container for holding an [`AsyncFactory`] instance and its result type.
+pub struct AsyncFactoryContainer<F, FactoryResult>(
+    pub(crate) F,
+    pub(crate) PhantomData<FactoryResult>,
+);
+/// A container for holding type of struct that can be built from specified dependencies with an
+/// asynchronous constructor.
+pub struct AsyncConstructorFactoryContainer<T>(pub(crate) PhantomData<T>);
+
+impl<F, FactoryResult> ScopeCache for AsyncFactoryContainer<F, FactoryResult> {
+    type Cache = ();
+}
+
+impl<F, FactoryResult> CollectMany for AsyncFactoryContainer<F, FactoryResult> {}
+
+impl<T> ScopeCache for AsyncConstructorFactoryContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for AsyncConstructorFactoryContainer<T> {}
+
+/// Polls both futures concurrently until both of them are complete.
+pub(crate) async fn join<A, B>(first: A, second: B) -> (A::Output, B::Output)
+where
+    A: Future,
+    B: Future,
+{
+    let mut first = pin!(first);
+    let mut second = pin!(second);
+    let mut first_output = None;
+    let mut second_output = None;
+
+    poll_fn(|cx| {
+        if first_output.is_none() {
+            if let Poll::Ready(output) = first.as_mut().poll(cx) {
+                first_output = Some(output);
+            }
+        }
+        if second_output.is_none() {
+            if let Poll::Ready(output) = second.as_mut().poll(cx) {
+                second_output = Some(output);
+            }
+        }
+
+        match (first_output.take(), second_output.take()) {
+            (Some(first), Some(second)) => Poll::Ready((first, second)),
+            (first, second) => {
+                first_output = first;
+                second_output = second;
+                Poll::Pending
+            }
+        }
+    })
+    .await
+}
+
+impl<C, T, Infer> AsyncInjector<T, SynchronousStrategy<Infer>> for C
+where
+    C: Injector<T, Infer>,
+    T: Send,
+{
+    /// Inject a dependency that is injected synchronously.
+    fn inject_async(self) -> impl Future<Output = T> + Send {
+        ready(self.inject())
+    }
+}
+
+impl<'a, Parent, Scope, F, FactoryInfer, T, Infer>
+    AsyncInjector<T, (Infer, AsyncFactoryStrategy<F, FactoryInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<AsyncFactoryContainer<F, T>, Infer>,
+    Self: AsyncListInjector<F::Dependencies<'a>, FactoryInfer>,
+    F: AsyncFactory<Result = T> + Sync + 'a,
+    T: 'a,
+{
+    fn inject_async(self) -> impl Future<Output = T> + Send {
+        let factory = &self.get().0;
+        let dependencies = self.inject_list_async();
+        async move { factory.build(dependencies.await).await }
+    }
+}
+
+impl<'a, Parent, Scope, ConstructorInfer, T, Infer>
+    AsyncInjector<T, (Infer, AsyncConstructorFactoryStrategy<ConstructorInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<AsyncConstructorFactoryContainer<T>, Infer>,
+    Self: AsyncListInjector<T::Dependencies<'a>, ConstructorInfer>,
+    T: AsyncConstructorFactory,
+{
+    fn inject_async(self) -> impl Future<Output = T> + Send {
+        let dependencies = self.inject_list_async();
+        async move { T::build(dependencies.await).await }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{sync::Arc, task::Wake};
+    use core::{
+        cell::Cell,
+        sync::atomic::{AtomicBool, Ordering},
+        task::{Context, Waker},
+    };
+
+    /// Resolves after being polled the specified number of times.
+    struct Yield(Cell<u32>);
+
+    impl Future for Yield {
+        type Output = ();
+
+        fn poll(self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let remaining = self.0.get();
+            if remaining == 0 {
+                Poll::Ready(())
+            } else {
+                self.0.set(remaining - 1);
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_inject_async() {
+        struct Config(&'static str);
+        struct DatabaseBuilt(AtomicBool);
+
+        struct Database(&'static str);
+
+        impl AsyncConstructorFactory for Database {
+            type Dependencies<'a> = (&'a Config, &'a DatabaseBuilt);
+
+            async fn build((config, built): Self::Dependencies<'_>) -> Self {
+                Yield(Cell::new(2)).await;
+                built.0.store(true, Ordering::Relaxed);
+                Self(config.0)
+            }
+        }
+
+        struct Cache;
+
+        struct CacheFactory;
+
+        impl AsyncFactory for CacheFactory {
+            type Result = Cache;
+            type Dependencies<'a> = (&'a DatabaseBuilt, ());
+
+            async fn build(&self, (database_built, ()): Self::Dependencies<'_>) -> Cache {
+                assert!(
+                    !database_built.0.load(Ordering::Relaxed),
+                    "built after the database instead of concurrently"
+                );
+                Yield(Cell::new(1)).await;
+                Cache
+            }
+        }
+
+        struct App(Database, Cache);
+
+        impl AsyncConstructorFactory for App {
+            type Dependencies<'a> = (Database, Cache);
+
+            async fn build((database, cache): Self::Dependencies<'_>) -> Self {
+                Self(database, cache)
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(Config("postgres://localhost"))
+            .with_singleton(DatabaseBuilt(AtomicBool::new(false)))
+            .with_async_constructor_factory::<Database>()
+            .with_async_factory(CacheFactory)
+            .with_async_constructor_factory::<App>();
+
+        let app = block_on(AsyncInjector::<App, _>::inject_async(&container));
+        assert_eq!(app.0 .0, "postgres://localhost");
+        let App(_, Cache) = app;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_inject_async_spawned() {
+        struct Config(&'static str);
+
+        struct Database(&'static str);
+
+        impl AsyncConstructorFactory for Database {
+            type Dependencies<'a> = (&'a Config, ());
+
+            async fn build((config, ()): Self::Dependencies<'_>) -> Self {
+                tokio::task::yield_now().await;
+                Self(config.0)
+            }
+        }
+
+        struct App(Database);
+
+        impl AsyncConstructorFactory for App {
+            type Dependencies<'a> = (Database, &'a Config);
+
+            async fn build((database, _config): Self::Dependencies<'_>) -> Self {
+                Self(database)
+            }
+        }
+
+        let container = Arc::new(
+            DependencyContainer::default()
+                .with_singleton(Config("postgres://localhost"))
+                .with_async_constructor_factory::<Database>()
+                .with_async_constructor_factory::<App>(),
+        );
+
+        let app =
+            tokio::spawn(async move { AsyncInjector::<App, _>::inject_async(&*container).await })
+                .await
+                .unwrap();
+        assert_eq!(app.0 .0, "postgres://localhost");
+    }
+}
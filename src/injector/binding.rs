@@ -0,0 +1,90 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use alloc::sync::Arc;
+use core::{convert::Infallible, marker::PhantomData, ops::Deref};
+
+/// A marker struct used to signify the strategy of injecting an immutable reference to an
+/// interface bound to an implementation stored in a `Pointer` in dependency injection.
+pub struct BindingStrategy<Pointer>(PhantomData<Pointer>, Infallible);
+/// A marker struct used to signify the strategy of cloning an [`Arc`] of an interface bound to an
+/// implementation in dependency injection.
+pub struct ArcBindingStrategy(Infallible);
+
+/// A container for holding an implementation of the `Interface` (usually a trait object) stored
+/// in a `Pointer` like [`Box`](alloc::boxed::Box) or [`Arc`].
+pub struct BindingContainer<Interface: ?Sized, Pointer>(
+    pub(crate) Pointer,
+    pub(crate) PhantomData<Interface>,
+);
+
+impl<Interface: ?Sized, Pointer> ScopeCache for BindingContainer<Interface, Pointer> {
+    type Cache = ();
+}
+
+impl<Interface: ?Sized, Pointer> CollectMany for BindingContainer<Interface, Pointer> {}
+
+impl<'a, Parent, Scope, Interface, Pointer, Infer>
+    Injector<&'a Interface, (Infer, BindingStrategy<Pointer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<BindingContainer<Interface, Pointer>, Infer>,
+    Interface: ?Sized,
+    Pointer: Deref<Target = Interface> + 'a,
+{
+    /// Inject an immutable reference to the interface.
+    fn inject(self) -> &'a Interface {
+        &self.get().0
+    }
+}
+
+impl<Parent, Scope, Interface, Infer> Injector<Arc<Interface>, (Infer, ArcBindingStrategy)>
+    for &DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>:
+        DepsListGetRef<BindingContainer<Interface, Arc<Interface>>, Infer>,
+    Interface: ?Sized,
+{
+    /// Inject the interface by cloning its [`Arc`].
+    fn inject(self) -> Arc<Interface> {
+        Arc::clone(&self.get().0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject() {
+        trait Repository {
+            fn name(&self) -> &'static str;
+        }
+
+        struct InMemoryRepository;
+
+        impl Repository for InMemoryRepository {
+            fn name(&self) -> &'static str {
+                "in-memory"
+            }
+        }
+
+        trait Analytics {}
+
+        struct InMemoryAnalytics;
+
+        impl Analytics for InMemoryAnalytics {}
+
+        let container = DependencyContainer::default()
+            .with_binding::<dyn Repository>(Box::new(InMemoryRepository))
+            .with_arc_binding::<dyn Analytics>(Arc::new(InMemoryAnalytics));
+
+        let repository: &dyn Repository = (&container).inject();
+        assert_eq!(repository.name(), "in-memory");
+
+        let _analytics: &dyn Analytics = (&container).inject();
+        let _analytics: Arc<dyn Analytics> = (&container).inject();
+    }
+}
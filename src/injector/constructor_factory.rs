@@ -1,4 +1,7 @@
-use super::{Injector, ListInjector};
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector, ListInjector,
+};
 use crate::{DependencyContainer, DepsListGetRef};
 use core::convert::Infallible;
 use core::marker::PhantomData;
@@ -37,6 +40,18 @@ pub struct ConstructorFactoryContainer<T>(pub(crate) PhantomData<T>);
 /// consuming references to them.
 pub struct RefConstructorFactoryContainer<T>(pub(crate) PhantomData<T>);
 
+impl<T> ScopeCache for ConstructorFactoryContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for ConstructorFactoryContainer<T> {}
+
+impl<T> ScopeCache for RefConstructorFactoryContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for RefConstructorFactoryContainer<T> {}
+
 impl<'a, Parent, Scope, ConstructorInfer, T, Infer>
     Injector<T, (Infer, ConstructorFactoryStrategy<ConstructorInfer>)>
     for &'a DependencyContainer<Parent, Scope>
@@ -110,4 +125,164 @@ mod tests {
         let _app: App = (&container).inject();
         let _another_app: AnotherApp = (&container).inject();
     }
+
+    #[test]
+    fn test_inject_cached() {
+        use core::cell::Cell;
+
+        struct BuildsCount(Cell<usize>);
+
+        #[derive(Clone)]
+        struct App;
+
+        impl ConstructorFactory for App {
+            type Dependencies<'a> = (&'a BuildsCount, ());
+
+            fn build((builds_count, ()): Self::Dependencies<'_>) -> Self {
+                builds_count.0.set(builds_count.0.get() + 1);
+                Self
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(BuildsCount(Cell::new(0)))
+            .with_cached_constructor_factory::<App>();
+
+        let _app: &App = (&container).inject();
+        let _app: App = (&container).inject();
+        let _app: &App = (&container).inject();
+
+        let builds_count: &BuildsCount = (&container).inject();
+        assert_eq!(builds_count.0.get(), 1);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive() {
+        use crate::injector::factories::ConstructorFactory;
+
+        #[derive(Clone, PartialEq, Eq, Debug)]
+        struct Database(&'static str);
+
+        #[derive(ConstructorFactory)]
+        struct Service(#[inject(clone)] Database);
+
+        #[derive(ConstructorFactory)]
+        struct App<T: Default> {
+            service: Service,
+            db: Database,
+            #[inject(default)]
+            state: T,
+            #[inject(skip = 42)]
+            answer: u32,
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(Database("postgres://localhost"))
+            .with_constructor_factory::<Service>()
+            .with_constructor_factory::<App<String>>();
+
+        let app: App<String> = (&container).inject();
+        assert_eq!(app.service.0, Database("postgres://localhost"));
+        assert_eq!(app.db, Database("postgres://localhost"));
+        assert_eq!(app.state, String::new());
+        assert_eq!(app.answer, 42);
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_ref() {
+        use crate::injector::factories::RefConstructorFactory;
+
+        trait Repository {}
+        struct InMemoryRepository;
+        impl Repository for InMemoryRepository {}
+
+        #[derive(Clone)]
+        struct Database;
+        struct Cache;
+
+        #[allow(dead_code)]
+        #[derive(RefConstructorFactory)]
+        struct App<'a> {
+            db: Database,
+            cache: &'a Cache,
+            repository: &'a dyn Repository,
+            #[inject(clone)]
+            cloned_db: Database,
+            #[inject(skip = "app")]
+            name: &'a str,
+        }
+
+        let repository: Box<dyn Repository> = Box::new(InMemoryRepository);
+        let container = DependencyContainer::default()
+            .with_singleton(Database)
+            .with_singleton(Cache)
+            .with_singleton(repository)
+            .with_ref_constructor_factory::<App>();
+
+        let app: App = (&container).inject();
+        assert_eq!(app.name, "app");
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_ref_binding() {
+        use crate::injector::factories::RefConstructorFactory;
+
+        trait Repository {}
+        struct InMemoryRepository;
+        impl Repository for InMemoryRepository {}
+
+        #[allow(dead_code)]
+        #[derive(RefConstructorFactory)]
+        struct App<'a> {
+            repository: &'a dyn Repository,
+        }
+
+        let container = DependencyContainer::default()
+            .with_binding::<dyn Repository>(Box::new(InMemoryRepository))
+            .with_ref_constructor_factory::<App>();
+
+        let _app: App = (&container).inject();
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_injectable() {
+        use crate::injector::factories::injectable;
+
+        #[derive(Clone)]
+        struct Database;
+        struct Cache;
+
+        #[allow(dead_code)]
+        struct App(Database);
+
+        #[injectable]
+        impl App {
+            fn new(db: &Database, _cache: &'_ Cache) -> Self {
+                Self(db.clone())
+            }
+        }
+
+        #[allow(dead_code)]
+        struct AnotherApp<'a>(&'a Cache, Database);
+
+        #[injectable]
+        impl<'a> AnotherApp<'a> {
+            fn new(cache: &'a Cache, db: Database) -> Self {
+                Self(cache, db)
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(Database)
+            .with_singleton(Cache)
+            .with_constructor_factory::<App>()
+            .with_ref_constructor_factory::<AnotherApp>();
+
+        let _app: App = (&container).inject();
+        let _another_app: AnotherApp = (&container).inject();
+    }
 }
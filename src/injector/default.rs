@@ -0,0 +1,94 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use core::{convert::Infallible, marker::PhantomData};
+
+/// A marker struct used to signify the default strategy in dependency injection.
+pub struct DefaultStrategy(Infallible);
+/// A marker struct used to signify the strategy of falling back to the default value of a
+/// dependency in dependency injection.
+pub struct DefaultedStrategy<Infer>(PhantomData<Infer>, Infallible);
+
+/// A container for holding type of struct that is created with [`Default::default`] on each
+/// injection.
+pub struct DefaultContainer<T>(pub(crate) PhantomData<T>);
+
+impl<T> ScopeCache for DefaultContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for DefaultContainer<T> {}
+
+/// A dependency that is injected from the container if it's registered or created with
+/// [`Default::default`] if it's declared absent with
+/// [`with_absent`](DependencyContainer::with_absent).
+///
+/// The fallback is chosen at compile time, so a dependency that is neither registered nor declared
+/// absent doesn't compile:
+///
+/// ```rust,compile_fail
+/// use rustyinject::{DependencyContainer, injector::{wrappers::Defaulted, Injector}};
+///
+/// #[derive(Default)]
+/// struct Timeouts(u32);
+///
+/// let container = DependencyContainer::default();
+/// let _: Defaulted<Timeouts> = (&container).inject();
+/// ```
+pub struct Defaulted<T>(pub T);
+
+impl<Parent, Scope, T, Infer> Injector<T, (Infer, DefaultStrategy)>
+    for &DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<DefaultContainer<T>, Infer>,
+    T: Default,
+{
+    /// Inject the default value of the dependency.
+    fn inject(self) -> T {
+        T::default()
+    }
+}
+
+impl<Parent, Scope, T, Infer> Injector<Defaulted<T>, DefaultedStrategy<Infer>>
+    for &DependencyContainer<Parent, Scope>
+where
+    Self: Injector<Option<T>, Infer>,
+    T: Default,
+{
+    /// Inject the dependency or its default value if it's declared absent.
+    fn inject(self) -> Defaulted<T> {
+        Defaulted(Injector::<Option<T>, Infer>::inject(self).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject() {
+        #[derive(Clone, Default, PartialEq, Eq, Debug)]
+        struct Config(u32);
+        #[derive(Default, PartialEq, Eq, Debug)]
+        struct Limits(u32);
+        #[derive(Default, PartialEq, Eq, Debug)]
+        struct Timeouts(u32);
+
+        let container = DependencyContainer::default()
+            .with_singleton(Config(8080))
+            .with_default::<Limits>()
+            .with_absent::<Timeouts>();
+
+        let limits: Limits = (&container).inject();
+        assert_eq!(limits, Limits(0));
+
+        let Defaulted(config): Defaulted<Config> = (&container).inject();
+        assert_eq!(config, Config(8080));
+        let Defaulted(limits): Defaulted<Limits> = (&container).inject();
+        assert_eq!(limits, Limits(0));
+        let Defaulted(timeouts): Defaulted<Timeouts> = (&container).inject();
+        assert_eq!(timeouts, Timeouts(0));
+    }
+}
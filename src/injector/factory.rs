@@ -1,4 +1,7 @@
-use super::{Injector, ListInjector};
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector, ListInjector,
+};
 use crate::{container::DependencyContainer, deps_list::DepsListGetRef};
 use core::{convert::Infallible, marker::PhantomData};
 
@@ -18,6 +21,12 @@ pub struct FactoryStrategy<F, FactoryInfer>(PhantomData<(F, FactoryInfer)>, Infa
 /// A container for holding a [`Factory`] instance and its result type.
 pub struct FactoryContainer<F, FactoryResult>(pub(crate) F, pub(crate) PhantomData<FactoryResult>);
 
+impl<F, FactoryResult> ScopeCache for FactoryContainer<F, FactoryResult> {
+    type Cache = ();
+}
+
+impl<F, FactoryResult> CollectMany for FactoryContainer<F, FactoryResult> {}
+
 impl<'a, Parent, Scope, F, FactoryInfer, T, Infer>
     Injector<T, (Infer, FactoryStrategy<F, FactoryInfer>)>
     for &'a DependencyContainer<Parent, Scope>
@@ -37,7 +46,7 @@ where
 pub trait RefFactory {
     /// A result of the factory [`build`](RefFactory::build) method.
     type Result<'a>;
-    /// Dependencies of the factory
+    /// Dependencies of the factory.
     type Dependencies<'a>;
 
     /// Build result from dependencies.
@@ -54,6 +63,12 @@ pub struct RefFactoryContainer<F, FactoryResult>(
     pub(crate) PhantomData<FactoryResult>,
 );
 
+impl<F, FactoryResult> ScopeCache for RefFactoryContainer<F, FactoryResult> {
+    type Cache = ();
+}
+
+impl<F, FactoryResult> CollectMany for RefFactoryContainer<F, FactoryResult> {}
+
 impl<'a, Parent, Scope, F, FactoryInfer, T, Infer>
     Injector<T, (Infer, RefFactoryStrategy<F, FactoryInfer>)>
     for &'a DependencyContainer<Parent, Scope>
@@ -68,11 +83,137 @@ where
     }
 }
 
+/// A family of types over a lifetime of borrowed dependencies. Used to name dependencies and
+/// results of [`FnFactory`] for any lifetime of the container borrow.
+///
+/// Own types with a lifetime join the family by implementing it for their `'static` version:
+/// `impl LifetimeFamily for App<'static> { type Of<'a> = App<'a>; }`.
+pub trait LifetimeFamily {
+    /// A member of the family for the specific lifetime.
+    type Of<'a>;
+}
+
+/// A [`LifetimeFamily`] of `&'a T`.
+pub struct Borrowed<T: ?Sized>(PhantomData<T>, Infallible);
+/// A [`LifetimeFamily`] of `T` that doesn't depend on the lifetime.
+pub struct Owned<T>(PhantomData<T>, Infallible);
+
+impl LifetimeFamily for () {
+    type Of<'a> = ();
+}
+
+impl<Head, Tail> LifetimeFamily for (Head, Tail)
+where
+    Head: LifetimeFamily,
+    Tail: LifetimeFamily,
+{
+    type Of<'a> = (Head::Of<'a>, Tail::Of<'a>);
+}
+
+/// Implements [`LifetimeFamily`] for flat tuples of the specified arity and all smaller ones
+/// except pairs, which are covered by the heterogeneously-typed list implementation.
+macro_rules! impl_flat_tuple_lifetime_family {
+    ($last:ident) => {
+        impl<$last: LifetimeFamily> LifetimeFamily for ($last,) {
+            type Of<'a> = ($last::Of<'a>,);
+        }
+    };
+    ($first:ident, $second:ident) => {
+        impl_flat_tuple_lifetime_family!($second);
+    };
+    ($first:ident $(, $rest:ident)*) => {
+        impl<$first: LifetimeFamily, $($rest: LifetimeFamily),*> LifetimeFamily
+            for ($first, $($rest,)*)
+        {
+            type Of<'a> = ($first::Of<'a>, $($rest::Of<'a>,)*);
+        }
+
+        impl_flat_tuple_lifetime_family!($($rest),*);
+    };
+}
+
+impl_flat_tuple_lifetime_family!(A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
+
+impl<T: ?Sized + 'static> LifetimeFamily for Borrowed<T> {
+    type Of<'a> = &'a T;
+}
+
+impl<T> LifetimeFamily for Owned<T> {
+    type Of<'a> = T;
+}
+
+/// A closure or a function pointer building a result that borrows from its dependencies for the
+/// lifetime `'a`. Implemented for every `Fn(Dependencies::Of<'a>) -> T::Of<'a>`.
+pub trait RefFn<'a, Dependencies, T>
+where
+    Dependencies: LifetimeFamily,
+    T: LifetimeFamily,
+{
+    /// Call the closure or the function pointer.
+    fn call(&self, dependencies: Dependencies::Of<'a>) -> T::Of<'a>;
+}
+
+impl<'a, F, Dependencies, T> RefFn<'a, Dependencies, T> for F
+where
+    F: Fn(Dependencies::Of<'a>) -> T::Of<'a>,
+    Dependencies: LifetimeFamily,
+    T: LifetimeFamily,
+{
+    fn call(&self, dependencies: Dependencies::Of<'a>) -> T::Of<'a> {
+        self(dependencies)
+    }
+}
+
+/// A [`Factory`] and [`RefFactory`] that builds its result with a closure or a function pointer
+/// accepting dependencies borrowed for any lifetime. Dependencies are named with a
+/// [`LifetimeFamily`], and so is the result when it's used as a [`RefFactory`].
+pub struct FnFactory<F, Dependencies, T>(F, PhantomData<fn(Dependencies) -> T>);
+
+/// A container for holding a [`FnFactory`] used as a [`Factory`].
+pub type FnFactoryContainer<F, Dependencies, T> =
+    FactoryContainer<FnFactory<F, Dependencies, T>, T>;
+/// A container for holding a [`FnFactory`] used as a [`RefFactory`].
+pub type RefFnFactoryContainer<'a, F, Dependencies, T> =
+    RefFactoryContainer<FnFactory<F, Dependencies, T>, <T as LifetimeFamily>::Of<'a>>;
+
+impl<F, Dependencies, T> FnFactory<F, Dependencies, T> {
+    /// Create a new factory from a closure or a function pointer.
+    pub const fn new(factory: F) -> Self {
+        Self(factory, PhantomData)
+    }
+}
+
+impl<F, Dependencies, T> Factory for FnFactory<F, Dependencies, T>
+where
+    F: for<'a> Fn(Dependencies::Of<'a>) -> T,
+    Dependencies: LifetimeFamily,
+{
+    type Result = T;
+    type Dependencies<'a> = Dependencies::Of<'a>;
+
+    fn build(&self, dependencies: Self::Dependencies<'_>) -> Self::Result {
+        (self.0)(dependencies)
+    }
+}
+
+impl<F, Dependencies, T> RefFactory for FnFactory<F, Dependencies, T>
+where
+    F: for<'a> RefFn<'a, Dependencies, T>,
+    Dependencies: LifetimeFamily,
+    T: LifetimeFamily,
+{
+    type Result<'a> = T::Of<'a>;
+    type Dependencies<'a> = Dependencies::Of<'a>;
+
+    fn build<'a>(&self, dependencies: Self::Dependencies<'a>) -> Self::Result<'a> {
+        self.0.call(dependencies)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::DepsListRemove;
-
     use super::*;
+    use crate::{deps, deps_pat, Deps};
 
     #[test]
     fn test_inject() {
@@ -88,10 +229,11 @@ mod tests {
 
         impl RefFactory for AnotherAppFactory {
             type Result<'a> = AnotherApp<'a>;
-            type Dependencies<'a> = (&'a Database, ());
+            type Dependencies<'a> = Deps![&'a Database];
 
             fn build<'a>(&self, dependencies: Self::Dependencies<'a>) -> Self::Result<'a> {
-                AnotherApp(dependencies.0)
+                let deps_pat![db] = dependencies;
+                AnotherApp(db)
             }
         }
 
@@ -105,11 +247,10 @@ mod tests {
 
         impl Factory for AppFactory {
             type Result = App;
-            type Dependencies<'a> = (&'a Database, (Cache, ()));
+            type Dependencies<'a> = Deps![&'a Database, Cache];
 
             fn build(&self, dependencies: Self::Dependencies<'_>) -> Self::Result {
-                let (db, dependencies): (&Database, (Cache, ())) = dependencies.remove();
-                let (cache, _dependencies) = dependencies.remove();
+                let deps_pat![db, cache] = dependencies;
 
                 App {
                     db: db.clone(),
@@ -126,5 +267,79 @@ mod tests {
 
         let _app: App = (&new_container).inject();
         let _another_app: AnotherApp = (&new_container).inject();
+
+        let db = Database;
+        let deps_pat![_app, _another_app]: Deps![App, AnotherApp] = deps![
+            AppFactory.build(deps![&db, Cache]),
+            AnotherAppFactory.build(deps![&db]),
+        ];
+    }
+
+    #[test]
+    fn test_inject_fn() {
+        #[derive(Clone)]
+        struct Database;
+
+        #[derive(Clone)]
+        struct Cache;
+
+        #[allow(dead_code)]
+        struct App(Database);
+
+        #[allow(dead_code)]
+        struct CachedApp(Database, Cache);
+
+        #[allow(dead_code)]
+        struct AnotherApp<'a>(&'a Database);
+
+        impl LifetimeFamily for AnotherApp<'static> {
+            type Of<'a> = AnotherApp<'a>;
+        }
+
+        fn build_another_app(dependencies: Deps![&Database]) -> AnotherApp<'_> {
+            let deps_pat![db] = dependencies;
+            AnotherApp(db)
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(Database)
+            .with_fn_factory::<Deps![Borrowed<Database>], _, _>(|deps_pat![db]| App(db.clone()))
+            .with_ref_fn_factory::<Deps![Borrowed<Database>], _, AnotherApp<'static>>(
+                build_another_app,
+            )
+            .with_singleton(Cache)
+            .with_fn_factory::<(Borrowed<Database>, Owned<Cache>, Borrowed<Cache>), _, _>(
+                |(db, cache, _cache)| CachedApp(db.clone(), cache),
+            );
+
+        let _app: App = (&container).inject();
+        let _another_app: AnotherApp = (&container).inject();
+        let _cached_app: CachedApp = (&container).inject();
+    }
+
+    #[test]
+    fn test_inject_fn_shared() {
+        use alloc::sync::Arc;
+        use std::thread;
+
+        #[derive(Clone)]
+        struct Database;
+
+        #[allow(dead_code)]
+        struct App(Database);
+
+        let container = DependencyContainer::default()
+            .with_singleton(Database)
+            .with_fn_factory::<Deps![Borrowed<Database>], _, _>(|deps_pat![db]| App(db.clone()));
+
+        let _app: App = (&container).inject();
+        let container = Arc::new(container);
+        let shared = Arc::clone(&container);
+        thread::spawn(move || {
+            let _app: App = (&*shared).inject();
+        })
+        .join()
+        .unwrap();
+        let _app: App = (&*container).inject();
     }
 }
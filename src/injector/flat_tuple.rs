@@ -0,0 +1,100 @@
+use super::{AsyncListInjector, Injector, ListInjector, TryInjector, TryListInjector};
+use crate::ResolveError;
+use core::{convert::Infallible, future::Future, marker::PhantomData};
+
+/// A marker struct used to signify injection of dependencies listed in a flat tuple like
+/// `(A, B, C)` instead of a heterogeneously-typed list like `(A, (B, (C, ())))`.
+pub struct FlatTupleStrategy<Infer>(PhantomData<Infer>, Infallible);
+
+/// Implements [`ListInjector`], [`TryListInjector`] and [`AsyncListInjector`] for flat tuples of
+/// the specified arity and all smaller ones.
+macro_rules! impl_flat_tuple_list_injector {
+    () => {};
+    (
+        $dependency:ident: $infer:ident => $binding:ident
+        $(, $rest_dependency:ident: $rest_infer:ident => $rest_binding:ident)*
+    ) => {
+        impl<'a, Container, $dependency, $infer, $($rest_dependency, $rest_infer),*>
+            ListInjector<
+                ($dependency, $($rest_dependency,)*),
+                FlatTupleStrategy<($infer, $($rest_infer,)*)>,
+            > for &'a Container
+        where
+            &'a Container: Injector<$dependency, $infer> $(+ Injector<$rest_dependency, $rest_infer>)*,
+        {
+            fn inject_list(self) -> ($dependency, $($rest_dependency,)*) {
+                (
+                    Injector::<$dependency, $infer>::inject(self),
+                    $(Injector::<$rest_dependency, $rest_infer>::inject(self),)*
+                )
+            }
+        }
+
+        impl<'a, Container, Error, $dependency, $infer, $($rest_dependency, $rest_infer),*>
+            TryListInjector<
+                ($dependency, $($rest_dependency,)*),
+                Error,
+                FlatTupleStrategy<($infer, $($rest_infer,)*)>,
+            > for &'a Container
+        where
+            &'a Container: TryInjector<$dependency, Error, $infer>
+                $(+ TryInjector<$rest_dependency, Error, $rest_infer>)*,
+        {
+            fn try_inject_list(
+                self,
+            ) -> Result<($dependency, $($rest_dependency,)*), ResolveError<Error>> {
+                Ok((
+                    TryInjector::<$dependency, Error, $infer>::try_inject(self)?,
+                    $(TryInjector::<$rest_dependency, Error, $rest_infer>::try_inject(self)?,)*
+                ))
+            }
+        }
+
+        impl<'a, Container, $dependency, $infer, $($rest_dependency, $rest_infer),*>
+            AsyncListInjector<
+                ($dependency, $($rest_dependency,)*),
+                FlatTupleStrategy<($infer, $($rest_infer,)*)>,
+            > for &'a Container
+        where
+            &'a Container: AsyncListInjector<
+                $crate::Deps![$dependency, $($rest_dependency),*],
+                $crate::Deps![$infer, $($rest_infer),*],
+            >,
+        {
+            /// Inject the dependencies concurrently like the heterogeneously-typed list does.
+            fn inject_list_async(
+                self,
+            ) -> impl Future<Output = ($dependency, $($rest_dependency,)*)> + Send {
+                let dependencies = AsyncListInjector::<
+                    $crate::Deps![$dependency, $($rest_dependency),*],
+                    $crate::Deps![$infer, $($rest_infer),*],
+                >::inject_list_async(self);
+                async move {
+                    let $crate::deps_pat![$binding, $($rest_binding),*] = dependencies.await;
+                    ($binding, $($rest_binding,)*)
+                }
+            }
+        }
+
+        impl_flat_tuple_list_injector!($($rest_dependency: $rest_infer => $rest_binding),*);
+    };
+}
+
+impl_flat_tuple_list_injector!(
+    A: AInfer => a_dependency,
+    B: BInfer => b_dependency,
+    C: CInfer => c_dependency,
+    D: DInfer => d_dependency,
+    E: EInfer => e_dependency,
+    F: FInfer => f_dependency,
+    G: GInfer => g_dependency,
+    H: HInfer => h_dependency,
+    I: IInfer => i_dependency,
+    J: JInfer => j_dependency,
+    K: KInfer => k_dependency,
+    L: LInfer => l_dependency,
+    M: MInfer => m_dependency,
+    N: NInfer => n_dependency,
+    O: OInfer => o_dependency,
+    P: PInfer => p_dependency
+);
@@ -0,0 +1,148 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    factories::ConstructorFactory,
+    Injector, ListInjector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use core::{cell::OnceCell, convert::Infallible, marker::PhantomData};
+use std::sync::OnceLock;
+
+/// A marker struct used to signify the lazy singleton strategy in dependency injection.
+pub struct LazySingletonStrategy<ConstructorInfer>(PhantomData<ConstructorInfer>, Infallible);
+/// A marker struct used to signify the thread-safe lazy singleton strategy in dependency
+/// injection.
+pub struct SyncLazySingletonStrategy<ConstructorInfer>(PhantomData<ConstructorInfer>, Infallible);
+/// A marker struct used to signify the strategy of cloning the lazy singleton in dependency
+/// injection.
+pub struct LazySingletonClonedStrategy<ConstructorInfer>(PhantomData<ConstructorInfer>, Infallible);
+/// A marker struct used to signify the strategy of cloning the thread-safe lazy singleton in
+/// dependency injection.
+pub struct SyncLazySingletonClonedStrategy<ConstructorInfer>(
+    PhantomData<ConstructorInfer>,
+    Infallible,
+);
+
+/// A container for holding a singleton instance of a dependency that is built from a constructor
+/// on the first injection.
+pub struct LazySingletonContainer<T>(pub(crate) OnceCell<T>);
+/// A thread-safe container for holding a singleton instance of a dependency that is built from a
+/// constructor on the first injection.
+pub struct SyncLazySingletonContainer<T>(pub(crate) OnceLock<T>);
+
+impl<T> ScopeCache for LazySingletonContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for LazySingletonContainer<T> {}
+
+impl<T> ScopeCache for SyncLazySingletonContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for SyncLazySingletonContainer<T> {}
+
+impl<'a, Parent, Scope, ConstructorInfer, T, Infer>
+    Injector<&'a T, (Infer, LazySingletonStrategy<ConstructorInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<LazySingletonContainer<T>, Infer>,
+    Self: ListInjector<T::Dependencies<'a>, ConstructorInfer>,
+    T: ConstructorFactory,
+{
+    /// Inject an immutable reference to the dependency(singleton) building it first if it wasn't
+    /// injected before.
+    fn inject(self) -> &'a T {
+        self.get().0.get_or_init(|| T::build(self.inject_list()))
+    }
+}
+
+impl<'a, Parent, Scope, ConstructorInfer, T, Infer>
+    Injector<&'a T, (Infer, SyncLazySingletonStrategy<ConstructorInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<SyncLazySingletonContainer<T>, Infer>,
+    Self: ListInjector<T::Dependencies<'a>, ConstructorInfer>,
+    T: ConstructorFactory,
+{
+    /// Inject an immutable reference to the dependency(singleton) building it first if it wasn't
+    /// injected before.
+    fn inject(self) -> &'a T {
+        self.get().0.get_or_init(|| T::build(self.inject_list()))
+    }
+}
+
+impl<'a, Parent, Scope, ConstructorInfer, T, Infer>
+    Injector<T, (Infer, LazySingletonClonedStrategy<ConstructorInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    Self: Injector<&'a T, (Infer, LazySingletonStrategy<ConstructorInfer>)>,
+    T: Clone + 'a,
+{
+    /// Inject a clone of the dependency(singleton) building it first if it wasn't injected
+    /// before.
+    fn inject(self) -> T {
+        self.inject().clone()
+    }
+}
+
+impl<'a, Parent, Scope, ConstructorInfer, T, Infer>
+    Injector<T, (Infer, SyncLazySingletonClonedStrategy<ConstructorInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    Self: Injector<&'a T, (Infer, SyncLazySingletonStrategy<ConstructorInfer>)>,
+    T: Clone + 'a,
+{
+    /// Inject a clone of the dependency(singleton) building it first if it wasn't injected
+    /// before.
+    fn inject(self) -> T {
+        self.inject().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_inject() {
+        struct BuildsCount(AtomicUsize);
+
+        #[derive(Clone)]
+        struct Client;
+
+        impl ConstructorFactory for Client {
+            type Dependencies<'a> = (&'a BuildsCount, ());
+
+            fn build((builds_count, ()): Self::Dependencies<'_>) -> Self {
+                builds_count.0.fetch_add(1, Ordering::Relaxed);
+                Self
+            }
+        }
+
+        fn assert_sync<T: Sync>(_value: &T) {}
+
+        let container = DependencyContainer::default()
+            .with_singleton(BuildsCount(AtomicUsize::new(0)))
+            .with_lazy_singleton::<Client>();
+
+        let builds_count: &BuildsCount = (&container).inject();
+        assert_eq!(builds_count.0.load(Ordering::Relaxed), 0);
+        let _client: &Client = (&container).inject();
+        let _client: &Client = (&container).inject();
+        assert_eq!(builds_count.0.load(Ordering::Relaxed), 1);
+
+        let container = Arc::new(
+            DependencyContainer::default()
+                .with_singleton(BuildsCount(AtomicUsize::new(0)))
+                .with_sync_lazy_singleton::<Client>(),
+        );
+        assert_sync(&container);
+
+        let builds_count: &BuildsCount = (&*container).inject();
+        let _client: &Client = (&*container).inject();
+        let _client: Client = (&*container).inject();
+        assert_eq!(builds_count.0.load(Ordering::Relaxed), 1);
+    }
+}
@@ -0,0 +1,222 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use core::convert::Infallible;
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// A marker struct used to signify the strategy of locking a singleton behind a mutex in
+/// dependency injection.
+pub struct MutexStrategy(Infallible);
+/// A marker struct used to signify the strategy of locking a singleton behind a readers-writer
+/// lock for reading or writing in dependency injection.
+pub struct RwLockStrategy(Infallible);
+
+/// A container for holding a singleton instance of a dependency behind a [`Mutex`].
+pub struct MutexContainer<T>(pub(crate) Mutex<T>);
+/// A container for holding a singleton instance of a dependency behind a [`RwLock`].
+pub struct RwLockContainer<T>(pub(crate) RwLock<T>);
+
+impl<T> ScopeCache for MutexContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for MutexContainer<T> {}
+
+impl<T> ScopeCache for RwLockContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for RwLockContainer<T> {}
+
+impl<'a, Parent, Scope, T, Infer> Injector<MutexGuard<'a, T>, (Infer, MutexStrategy)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<MutexContainer<T>, Infer>,
+{
+    /// Inject a guard of the locked dependency(singleton) blocking the current thread until the
+    /// mutex is acquired. A poisoned mutex is locked anyway.
+    fn inject(self) -> MutexGuard<'a, T> {
+        self.get().0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<'a, Parent, Scope, T, Infer> Injector<RwLockReadGuard<'a, T>, (Infer, RwLockStrategy)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<RwLockContainer<T>, Infer>,
+{
+    /// Inject a guard of the dependency(singleton) locked for reading blocking the current thread
+    /// until the lock is acquired. A poisoned lock is locked anyway.
+    fn inject(self) -> RwLockReadGuard<'a, T> {
+        self.get().0.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<'a, Parent, Scope, T, Infer> Injector<RwLockWriteGuard<'a, T>, (Infer, RwLockStrategy)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<RwLockContainer<T>, Infer>,
+{
+    /// Inject a guard of the dependency(singleton) locked for writing blocking the current thread
+    /// until the lock is acquired. A poisoned lock is locked anyway.
+    fn inject(self) -> RwLockWriteGuard<'a, T> {
+        self.get().0.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "async")]
+pub use tokio_lock::{TokioMutexContainer, TokioRwLockContainer};
+
+#[cfg(feature = "async")]
+mod tokio_lock {
+    use super::{MutexStrategy, RwLockStrategy};
+    use crate::{
+        injector::{
+            containers::{CollectMany, ScopeCache},
+            AsyncInjector, Injector,
+        },
+        DependencyContainer, DepsListGetRef,
+    };
+    use core::future::Future;
+    use tokio::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+    /// A container for holding a singleton instance of a dependency behind a tokio [`Mutex`].
+    pub struct TokioMutexContainer<T>(pub(crate) Mutex<T>);
+    /// A container for holding a singleton instance of a dependency behind a tokio [`RwLock`].
+    pub struct TokioRwLockContainer<T>(pub(crate) RwLock<T>);
+
+    impl<T> ScopeCache for TokioMutexContainer<T> {
+        type Cache = ();
+    }
+
+    impl<T> CollectMany for TokioMutexContainer<T> {}
+
+    impl<T> ScopeCache for TokioRwLockContainer<T> {
+        type Cache = ();
+    }
+
+    impl<T> CollectMany for TokioRwLockContainer<T> {}
+
+    impl<'a, Parent, Scope, T, Infer> Injector<&'a Mutex<T>, (Infer, MutexStrategy)>
+        for &'a DependencyContainer<Parent, Scope>
+    where
+        DependencyContainer<Parent, Scope>: DepsListGetRef<TokioMutexContainer<T>, Infer>,
+    {
+        /// Inject an immutable reference to the mutex with the dependency(singleton) to lock it
+        /// later.
+        fn inject(self) -> &'a Mutex<T> {
+            &self.get().0
+        }
+    }
+
+    impl<'a, Parent, Scope, T, Infer> Injector<&'a RwLock<T>, (Infer, RwLockStrategy)>
+        for &'a DependencyContainer<Parent, Scope>
+    where
+        DependencyContainer<Parent, Scope>: DepsListGetRef<TokioRwLockContainer<T>, Infer>,
+    {
+        /// Inject an immutable reference to the readers-writer lock with the
+        /// dependency(singleton) to lock it later.
+        fn inject(self) -> &'a RwLock<T> {
+            &self.get().0
+        }
+    }
+
+    impl<'a, Parent, Scope, T, Infer> AsyncInjector<MutexGuard<'a, T>, (Infer, MutexStrategy)>
+        for &'a DependencyContainer<Parent, Scope>
+    where
+        DependencyContainer<Parent, Scope>: DepsListGetRef<TokioMutexContainer<T>, Infer>,
+        T: Send,
+    {
+        /// Inject a guard of the locked dependency(singleton) waiting until the mutex is acquired.
+        fn inject_async(self) -> impl Future<Output = MutexGuard<'a, T>> + Send {
+            self.get().0.lock()
+        }
+    }
+
+    impl<'a, Parent, Scope, T, Infer> AsyncInjector<RwLockReadGuard<'a, T>, (Infer, RwLockStrategy)>
+        for &'a DependencyContainer<Parent, Scope>
+    where
+        DependencyContainer<Parent, Scope>: DepsListGetRef<TokioRwLockContainer<T>, Infer>,
+        T: Send + Sync,
+    {
+        /// Inject a guard of the dependency(singleton) locked for reading waiting until the lock
+        /// is acquired.
+        fn inject_async(self) -> impl Future<Output = RwLockReadGuard<'a, T>> + Send {
+            self.get().0.read()
+        }
+    }
+
+    impl<'a, Parent, Scope, T, Infer>
+        AsyncInjector<RwLockWriteGuard<'a, T>, (Infer, RwLockStrategy)>
+        for &'a DependencyContainer<Parent, Scope>
+    where
+        DependencyContainer<Parent, Scope>: DepsListGetRef<TokioRwLockContainer<T>, Infer>,
+        T: Send + Sync,
+    {
+        /// Inject a guard of the dependency(singleton) locked for writing waiting until the lock
+        /// is acquired.
+        fn inject_async(self) -> impl Future<Output = RwLockWriteGuard<'a, T>> + Send {
+            self.get().0.write()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject() {
+        struct Counter(u32);
+        struct Config(u32);
+
+        let container = DependencyContainer::default()
+            .with_mutex_singleton(Counter(0))
+            .with_rwlock_singleton(Config(0));
+
+        let mut counter: MutexGuard<Counter> = (&container).inject();
+        counter.0 += 1;
+        drop(counter);
+        let mut config: RwLockWriteGuard<Config> = (&container).inject();
+        config.0 += 1;
+        drop(config);
+
+        let counter: MutexGuard<Counter> = (&container).inject();
+        let first_config: RwLockReadGuard<Config> = (&container).inject();
+        let second_config: RwLockReadGuard<Config> = (&container).inject();
+        assert_eq!((counter.0, first_config.0, second_config.0), (1, 1, 1));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_inject_tokio() {
+        use crate::injector::AsyncInjector;
+        use tokio::sync::{Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+        struct Counter(u32);
+        struct Config(u32);
+
+        let container = DependencyContainer::default()
+            .with_tokio_mutex_singleton(Counter(0))
+            .with_tokio_rwlock_singleton(Config(0));
+
+        let counter: &Mutex<Counter> = (&container).inject();
+        counter.lock().await.0 += 1;
+        let config: &RwLock<Config> = (&container).inject();
+        config.write().await.0 += 1;
+
+        let mut counter = AsyncInjector::<MutexGuard<Counter>, _>::inject_async(&container).await;
+        counter.0 += 1;
+        drop(counter);
+        let mut config =
+            AsyncInjector::<RwLockWriteGuard<Config>, _>::inject_async(&container).await;
+        config.0 += 1;
+        drop(config);
+
+        let counter = AsyncInjector::<MutexGuard<Counter>, _>::inject_async(&container).await;
+        let config = AsyncInjector::<RwLockReadGuard<Config>, _>::inject_async(&container).await;
+        assert_eq!((counter.0, config.0), (2, 2));
+    }
+}
@@ -0,0 +1,213 @@
+use super::{containers::ScopeCache, Injector};
+use crate::DependencyContainer;
+use alloc::{boxed::Box, rc::Rc, sync::Arc, vec::Vec};
+use core::{any::Any, convert::Infallible, slice};
+
+/// A trait for containers, lists of containers and container references from which all
+/// dependencies added with [`with_multi`](DependencyContainer::with_multi) are collected.
+///
+/// Containers that don't hold such dependencies use the default implementation that collects
+/// nothing.
+pub trait CollectMany {
+    /// Collect immutable references to all dependencies of type `T` in registration order.
+    fn collect_many<'a, T>(&'a self, _many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+    }
+}
+
+impl CollectMany for () {}
+
+impl<Head, Tail> CollectMany for (Head, Tail)
+where
+    Head: CollectMany,
+    Tail: CollectMany,
+{
+    fn collect_many<'a, T>(&'a self, many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+        // Elements are prepended, so the tail is registered earlier than the head.
+        self.1.collect_many(many);
+        self.0.collect_many(many);
+    }
+}
+
+impl<D> CollectMany for &D
+where
+    D: CollectMany,
+{
+    fn collect_many<'a, T>(&'a self, many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+        (**self).collect_many(many);
+    }
+}
+
+impl<D> CollectMany for Box<D>
+where
+    D: CollectMany,
+{
+    fn collect_many<'a, T>(&'a self, many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+        (**self).collect_many(many);
+    }
+}
+
+impl<D> CollectMany for Rc<D>
+where
+    D: CollectMany,
+{
+    fn collect_many<'a, T>(&'a self, many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+        (**self).collect_many(many);
+    }
+}
+
+impl<D> CollectMany for Arc<D>
+where
+    D: CollectMany,
+{
+    fn collect_many<'a, T>(&'a self, many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+        (**self).collect_many(many);
+    }
+}
+
+/// A marker struct used to signify the strategy of injecting all dependencies of a type in
+/// dependency injection.
+pub struct ManyStrategy(Infallible);
+
+/// A container for holding one of many boxed dependencies of the same type.
+pub struct MultiContainer<T: ?Sized>(pub(crate) Box<T>);
+
+impl<T: ?Sized> ScopeCache for MultiContainer<T> {
+    type Cache = ();
+}
+
+impl<U> CollectMany for MultiContainer<U>
+where
+    U: ?Sized + 'static,
+{
+    fn collect_many<'a, T>(&'a self, many: &mut Vec<&'a T>)
+    where
+        T: ?Sized + 'static,
+    {
+        let dependency: &dyn Any = &self.0;
+        if let Some(dependency) = dependency.downcast_ref::<Box<T>>() {
+            many.push(dependency);
+        }
+    }
+}
+
+/// Immutable references to all dependencies of a type added with
+/// [`with_multi`](DependencyContainer::with_multi). Dependencies of parent containers go first,
+/// then dependencies of the container itself in registration order.
+pub struct Many<'a, T: ?Sized>(Vec<&'a T>);
+
+impl<'a, T: ?Sized> Many<'a, T> {
+    /// Iterate over the dependencies.
+    pub fn iter(&self) -> core::iter::Copied<slice::Iter<'_, &'a T>> {
+        self.0.iter().copied()
+    }
+
+    /// Number of the dependencies.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check whether there are no dependencies.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'a, T: ?Sized> IntoIterator for Many<'a, T> {
+    type Item = &'a T;
+    type IntoIter = alloc::vec::IntoIter<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a, 'many, T: ?Sized> IntoIterator for &'many Many<'a, T> {
+    type Item = &'a T;
+    type IntoIter = core::iter::Copied<slice::Iter<'many, &'a T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, Parent, Scope, T> Injector<Many<'a, T>, ManyStrategy>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: CollectMany,
+    T: ?Sized + 'static,
+{
+    /// Inject immutable references to all dependencies of the type.
+    fn inject(self) -> Many<'a, T> {
+        let mut many = Vec::new();
+        self.collect_many(&mut many);
+
+        Many(many)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject() {
+        trait Sink {
+            fn name(&self) -> &'static str;
+        }
+
+        struct Console;
+        impl Sink for Console {
+            fn name(&self) -> &'static str {
+                "console"
+            }
+        }
+
+        struct File;
+        impl Sink for File {
+            fn name(&self) -> &'static str {
+                "file"
+            }
+        }
+
+        struct Network;
+        impl Sink for Network {
+            fn name(&self) -> &'static str {
+                "network"
+            }
+        }
+
+        let parent = DependencyContainer::default()
+            .with_multi::<dyn Sink>(Box::new(Console))
+            .with_singleton(Console);
+        let container = DependencyContainer::new(&parent)
+            .with_multi::<dyn Sink>(Box::new(File))
+            .with_multi::<dyn Sink>(Box::new(Network));
+
+        let sinks: Many<dyn Sink> = (&container).inject();
+        let names: Vec<_> = sinks.iter().map(Sink::name).collect();
+        assert_eq!(names, ["console", "file", "network"]);
+
+        let sinks: Many<dyn Sink> = (&parent).inject();
+        assert_eq!(sinks.len(), 1);
+    }
+}
@@ -0,0 +1,214 @@
+use super::{
+    containers::{CollectMany, FactoryContainer, ScopeCache, SingletonContainer},
+    factories::Factory,
+    Injector, ListInjector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use core::{
+    convert::Infallible,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// A marker struct used to signify the strategy of injecting an immutable reference to a named
+/// singleton in dependency injection.
+pub struct NamedStrategy(Infallible);
+/// A marker struct used to signify the strategy of cloning a named singleton in dependency
+/// injection.
+pub struct NamedClonedStrategy(Infallible);
+/// A marker struct used to signify the strategy of building a dependency with a named factory in
+/// dependency injection.
+pub struct NamedFactoryStrategy<F, FactoryInfer>(PhantomData<(F, FactoryInfer)>, Infallible);
+
+/// A container for holding another container qualified with a `Tag`, so several dependencies of
+/// the same type can be told apart.
+pub struct NamedContainer<Tag, C>(pub(crate) C, pub(crate) PhantomData<Tag>);
+
+/// A container for holding a singleton qualified with a `Tag`.
+pub type NamedSingletonContainer<Tag, T> = NamedContainer<Tag, SingletonContainer<T>>;
+/// A container for holding a [`Factory`] instance qualified with a `Tag`.
+pub type NamedFactoryContainer<Tag, F> =
+    NamedContainer<Tag, FactoryContainer<F, <F as Factory>::Result>>;
+
+impl<Tag, C> ScopeCache for NamedContainer<Tag, C> {
+    type Cache = ();
+}
+
+impl<Tag, C> CollectMany for NamedContainer<Tag, C> {}
+
+/// An immutable reference to a singleton registered with
+/// [`with_named_singleton`](DependencyContainer::with_named_singleton) under the `Tag`.
+pub struct Named<'a, Tag, T> {
+    dependency: &'a T,
+    tag: PhantomData<Tag>,
+}
+
+impl<'a, Tag, T> Named<'a, Tag, T> {
+    /// Get the immutable reference to the dependency.
+    #[must_use]
+    pub const fn into_inner(self) -> &'a T {
+        self.dependency
+    }
+}
+
+impl<Tag, T> Deref for Named<'_, Tag, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.dependency
+    }
+}
+
+/// A dependency cloned from a singleton registered with
+/// [`with_named_singleton`](DependencyContainer::with_named_singleton) or built by a factory
+/// registered with [`with_named_factory`](DependencyContainer::with_named_factory) under the
+/// `Tag`.
+pub struct NamedValue<Tag, T> {
+    dependency: T,
+    tag: PhantomData<Tag>,
+}
+
+impl<Tag, T> NamedValue<Tag, T> {
+    const fn new(dependency: T) -> Self {
+        Self {
+            dependency,
+            tag: PhantomData,
+        }
+    }
+
+    /// Get the dependency.
+    pub fn into_inner(self) -> T {
+        self.dependency
+    }
+}
+
+impl<Tag, T> Deref for NamedValue<Tag, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.dependency
+    }
+}
+
+impl<Tag, T> DerefMut for NamedValue<Tag, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.dependency
+    }
+}
+
+impl<'a, Parent, Scope, Tag, T, Infer> Injector<Named<'a, Tag, T>, (Infer, NamedStrategy)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<NamedSingletonContainer<Tag, T>, Infer>,
+    Tag: 'a,
+{
+    /// Inject an immutable reference to the named dependency(singleton).
+    fn inject(self) -> Named<'a, Tag, T> {
+        Named {
+            dependency: &self.get().0 .0,
+            tag: PhantomData,
+        }
+    }
+}
+
+impl<Parent, Scope, Tag, T, Infer> Injector<NamedValue<Tag, T>, (Infer, NamedClonedStrategy)>
+    for &DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<NamedSingletonContainer<Tag, T>, Infer>,
+    T: Clone,
+{
+    /// Inject the named dependency(singleton) by cloning it from the container.
+    fn inject(self) -> NamedValue<Tag, T> {
+        NamedValue::new(self.get().0 .0.clone())
+    }
+}
+
+impl<'a, Parent, Scope, Tag, F, FactoryInfer, T, Infer>
+    Injector<NamedValue<Tag, T>, (Infer, NamedFactoryStrategy<F, FactoryInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    Self: DepsListGetRef<NamedContainer<Tag, FactoryContainer<F, T>>, Infer>
+        + ListInjector<F::Dependencies<'a>, FactoryInfer>,
+    F: Factory<Result = T>,
+{
+    /// Inject the dependency built by the named factory.
+    fn inject(self) -> NamedValue<Tag, T> {
+        let factory = &self.get().0 .0;
+        NamedValue::new(factory.build(self.inject_list()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::injector::factories::RefConstructorFactory;
+
+    #[test]
+    fn test_inject() {
+        trait Repository {
+            fn name(&self) -> &'static str;
+        }
+
+        struct InMemoryRepository(&'static str);
+
+        impl Repository for InMemoryRepository {
+            fn name(&self) -> &'static str {
+                self.0
+            }
+        }
+
+        struct Primary;
+        struct ReadReplica;
+
+        struct App<'a> {
+            primary: Named<'a, Primary, Box<dyn Repository>>,
+            replica: Named<'a, ReadReplica, Box<dyn Repository>>,
+        }
+
+        impl<'a> RefConstructorFactory<'a> for App<'a> {
+            type Dependencies = (
+                Named<'a, Primary, Box<dyn Repository>>,
+                (Named<'a, ReadReplica, Box<dyn Repository>>, ()),
+            );
+
+            fn build((primary, (replica, ())): Self::Dependencies) -> Self {
+                Self { primary, replica }
+            }
+        }
+
+        #[derive(Clone)]
+        struct Url(&'static str);
+
+        struct Connection(&'static str);
+
+        struct ConnectionFactory;
+
+        impl Factory for ConnectionFactory {
+            type Result = Connection;
+            type Dependencies<'a> = (NamedValue<ReadReplica, Url>, ());
+
+            fn build(&self, (url, ()): Self::Dependencies<'_>) -> Connection {
+                Connection(url.into_inner().0)
+            }
+        }
+
+        let primary: Box<dyn Repository> = Box::new(InMemoryRepository("primary"));
+        let replica: Box<dyn Repository> = Box::new(InMemoryRepository("replica"));
+        let container = DependencyContainer::default()
+            .with_named_singleton::<Primary, _>(primary)
+            .with_named_singleton::<ReadReplica, _>(replica)
+            .with_named_singleton::<Primary, _>(Url("primary-url"))
+            .with_named_singleton::<ReadReplica, _>(Url("replica-url"))
+            .with_named_factory::<ReadReplica, _>(ConnectionFactory)
+            .with_ref_constructor_factory::<App>();
+
+        let app: App = (&container).inject();
+        assert_eq!(app.primary.name(), "primary");
+        assert_eq!(app.replica.name(), "replica");
+
+        let url: NamedValue<Primary, Url> = (&container).inject();
+        assert_eq!(url.0, "primary-url");
+        let connection: NamedValue<ReadReplica, Connection> = (&container).inject();
+        assert_eq!(connection.0, "replica-url");
+    }
+}
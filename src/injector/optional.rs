@@ -0,0 +1,85 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use core::{convert::Infallible, marker::PhantomData};
+
+/// A marker struct used to signify the strategy of injecting [`None`] for a dependency declared
+/// absent in dependency injection.
+pub struct AbsentStrategy(Infallible);
+/// A marker struct used to signify the strategy of injecting [`None`] instead of an immutable
+/// reference to a dependency declared absent in dependency injection.
+pub struct AbsentRefStrategy(Infallible);
+/// A marker struct used to signify the strategy of injecting a registered dependency wrapped in
+/// [`Some`] in dependency injection.
+pub struct OptionalStrategy<Infer>(PhantomData<Infer>, Infallible);
+
+/// A container for declaring that a dependency isn't registered, so [`Option`] of it is resolved
+/// as [`None`].
+pub struct AbsentContainer<T>(pub(crate) PhantomData<T>);
+
+impl<T> ScopeCache for AbsentContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for AbsentContainer<T> {}
+
+impl<Parent, Scope, T, Infer> Injector<Option<T>, (Infer, AbsentStrategy)>
+    for &DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<AbsentContainer<T>, Infer>,
+{
+    /// Inject [`None`] as the dependency is declared absent.
+    fn inject(self) -> Option<T> {
+        None
+    }
+}
+
+impl<'a, Parent, Scope, T, Infer> Injector<Option<&'a T>, (Infer, AbsentRefStrategy)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<AbsentContainer<T>, Infer>,
+{
+    /// Inject [`None`] as the dependency is declared absent.
+    fn inject(self) -> Option<&'a T> {
+        None
+    }
+}
+
+impl<Parent, Scope, T, Infer> Injector<Option<T>, OptionalStrategy<Infer>>
+    for &DependencyContainer<Parent, Scope>
+where
+    Self: Injector<T, Infer>,
+{
+    /// Inject the registered dependency wrapped in [`Some`].
+    fn inject(self) -> Option<T> {
+        Some(self.inject())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject() {
+        #[derive(Clone)]
+        struct Metrics;
+        struct Tracing;
+
+        let container = DependencyContainer::default()
+            .with_singleton(Metrics)
+            .with_absent::<Tracing>();
+
+        let metrics: Option<&Metrics> = (&container).inject();
+        assert!(metrics.is_some());
+        let metrics: Option<Metrics> = (&container).inject();
+        assert!(metrics.is_some());
+
+        let tracing: Option<&Tracing> = (&container).inject();
+        assert!(tracing.is_none());
+        let tracing: Option<Tracing> = (&container).inject();
+        assert!(tracing.is_none());
+    }
+}
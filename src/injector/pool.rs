@@ -0,0 +1,166 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    factories::Factory,
+    Injector, ListInjector,
+};
+use crate::{DependencyContainer, DepsListGetRef};
+use alloc::vec::Vec;
+use core::{
+    convert::Infallible,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+use std::sync::{Mutex, PoisonError};
+
+/// A marker struct used to signify the pool strategy in dependency injection.
+pub struct PoolStrategy<F, FactoryInfer>(PhantomData<(F, FactoryInfer)>, Infallible);
+
+/// Idle objects of a pool.
+pub(crate) struct Pool<T> {
+    idle: Mutex<Vec<T>>,
+    max_idle: usize,
+}
+
+impl<T> Pool<T> {
+    fn new(max_idle: usize) -> Self {
+        Self {
+            idle: Mutex::new(Vec::with_capacity(max_idle)),
+            max_idle,
+        }
+    }
+
+    fn take(&self) -> Option<T> {
+        self.idle
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop()
+    }
+
+    fn give_back(&self, object: T) {
+        let mut idle = self.idle.lock().unwrap_or_else(PoisonError::into_inner);
+        if idle.len() < self.max_idle {
+            idle.push(object);
+        }
+    }
+}
+
+/// A container for holding a [`Factory`] instance and a pool of objects built by it.
+pub struct PoolContainer<F, T>(pub(crate) F, pub(crate) Pool<T>);
+
+impl<F, T> PoolContainer<F, T> {
+    pub(crate) fn new(factory: F, max_idle: usize) -> Self {
+        Self(factory, Pool::new(max_idle))
+    }
+}
+
+impl<F, T> ScopeCache for PoolContainer<F, T> {
+    type Cache = ();
+}
+
+impl<F, T> CollectMany for PoolContainer<F, T> {}
+
+/// An object taken from a pool that is returned to the pool on drop.
+///
+/// If the pool already holds its maximum number of idle objects, the object is dropped instead.
+pub struct Pooled<'a, T> {
+    /// Taken out only on drop.
+    object: Option<T>,
+    pool: &'a Pool<T>,
+}
+
+impl<T> Deref for Pooled<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.object.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for Pooled<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.object.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for Pooled<'_, T> {
+    fn drop(&mut self) {
+        if let Some(object) = self.object.take() {
+            self.pool.give_back(object);
+        }
+    }
+}
+
+impl<'a, Parent, Scope, F, FactoryInfer, T, Infer>
+    Injector<Pooled<'a, T>, (Infer, PoolStrategy<F, FactoryInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<PoolContainer<F, T>, Infer>,
+    Self: ListInjector<F::Dependencies<'a>, FactoryInfer>,
+    F: Factory<Result = T> + 'a,
+{
+    /// Inject an idle object of the pool or build a new one if there are no idle objects.
+    fn inject(self) -> Pooled<'a, T> {
+        let PoolContainer(factory, pool) = self.get();
+        let object = pool
+            .take()
+            .unwrap_or_else(|| factory.build(self.inject_list()));
+
+        Pooled {
+            object: Some(object),
+            pool,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::injector::factories::RefConstructorFactory;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_inject() {
+        struct BuildsCount(Cell<usize>);
+
+        struct Connection(u32);
+
+        struct ConnectionFactory;
+
+        impl Factory for ConnectionFactory {
+            type Result = Connection;
+            type Dependencies<'a> = (&'a BuildsCount, ());
+
+            fn build(&self, (builds_count, ()): Self::Dependencies<'_>) -> Connection {
+                builds_count.0.set(builds_count.0.get() + 1);
+                Connection(0)
+            }
+        }
+
+        struct Handler<'a>(Pooled<'a, Connection>);
+
+        impl<'a> RefConstructorFactory<'a> for Handler<'a> {
+            type Dependencies = (Pooled<'a, Connection>, ());
+
+            fn build((connection, ()): Self::Dependencies) -> Self {
+                Self(connection)
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(BuildsCount(Cell::new(0)))
+            .with_pool(ConnectionFactory, 1)
+            .with_ref_constructor_factory::<Handler>();
+        let builds_count: &BuildsCount = (&container).inject();
+
+        let mut first: Pooled<Connection> = (&container).inject();
+        first.0 += 1;
+        let second: Pooled<Connection> = (&container).inject();
+        assert_eq!(builds_count.0.get(), 2);
+        drop((first, second));
+
+        let first: Pooled<Connection> = (&container).inject();
+        assert_eq!((builds_count.0.get(), first.0), (2, 1));
+        let handler: Handler = (&container).inject();
+        assert_eq!((builds_count.0.get(), handler.0 .0), (3, 0));
+    }
+}
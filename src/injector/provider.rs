@@ -0,0 +1,87 @@
+use super::Injector;
+use crate::DependencyContainer;
+use alloc::boxed::Box;
+use core::{convert::Infallible, marker::PhantomData};
+
+/// A marker struct used to signify the provider strategy in dependency injection.
+pub struct ProviderStrategy<Infer>(PhantomData<Infer>, Infallible);
+
+/// A handle that injects a dependency from the container each time [`get`](Provider::get) is
+/// called instead of injecting it right away.
+///
+/// The provider is `Send` and `Sync`, so it can be held across `.await`, which requires the
+/// container to be `Sync`.
+pub struct Provider<'a, T> {
+    inject: Box<dyn Fn() -> T + Send + Sync + 'a>,
+}
+
+impl<T> Provider<'_, T> {
+    /// Inject the dependency.
+    #[must_use]
+    pub fn get(&self) -> T {
+        (self.inject)()
+    }
+}
+
+impl<'a, Parent, Scope, T, Infer> Injector<Provider<'a, T>, ProviderStrategy<Infer>>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    Self: Injector<T, Infer>,
+    DependencyContainer<Parent, Scope>: Sync,
+{
+    /// Inject a provider that captures an immutable reference to the container.
+    fn inject(self) -> Provider<'a, T> {
+        Provider {
+            inject: Box::new(move || Injector::<T, Infer>::inject(self)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::injector::factories::{ConstructorFactory, RefConstructorFactory};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_inject() {
+        fn assert_send_sync<T: Send + Sync>(_value: &T) {}
+
+        struct BuildsCount(AtomicUsize);
+
+        struct Report;
+
+        impl ConstructorFactory for Report {
+            type Dependencies<'a> = (&'a BuildsCount, ());
+
+            fn build((builds_count, ()): Self::Dependencies<'_>) -> Self {
+                builds_count.0.fetch_add(1, Ordering::Relaxed);
+                Self
+            }
+        }
+
+        struct Service<'a>(Provider<'a, Report>);
+
+        impl<'a> RefConstructorFactory<'a> for Service<'a> {
+            type Dependencies = (Provider<'a, Report>, ());
+
+            fn build((reports, ()): Self::Dependencies) -> Self {
+                Self(reports)
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(BuildsCount(AtomicUsize::new(0)))
+            .with_constructor_factory::<Report>()
+            .with_ref_constructor_factory::<Service>();
+        let builds_count: &BuildsCount = (&container).inject();
+
+        let service: Service = (&container).inject();
+        assert_send_sync(&service.0);
+        assert_eq!(builds_count.0.load(Ordering::Relaxed), 0);
+
+        let _report = service.0.get();
+        let _report = service.0.get();
+        assert_eq!(builds_count.0.load(Ordering::Relaxed), 2);
+    }
+}
@@ -0,0 +1,100 @@
+use super::{containers::CollectMany, factories::ConstructorFactory, Injector, ListInjector};
+use crate::{DependencyContainer, DepsListGetRef};
+use core::{cell::OnceCell, convert::Infallible, marker::PhantomData};
+
+/// A trait describing what is stored in a child scope created with
+/// [`DependencyContainer::scope`] for a container or a list of containers.
+pub trait ScopeCache {
+    /// Storage of the child scope.
+    type Cache: Default;
+}
+
+impl ScopeCache for () {
+    type Cache = ();
+}
+
+impl<Head, Tail> ScopeCache for (Head, Tail)
+where
+    Head: ScopeCache,
+    Tail: ScopeCache,
+{
+    type Cache = (Head::Cache, Tail::Cache);
+}
+
+/// A marker struct used to signify the scoped factory strategy in dependency injection.
+pub struct ScopedFactoryStrategy<ConstructorInfer>(PhantomData<ConstructorInfer>, Infallible);
+
+/// A container for holding type of struct that is built from a constructor at most once per child
+/// scope.
+pub struct ScopedFactoryContainer<T>(pub(crate) PhantomData<T>);
+/// A container of a child scope for holding an instance built by a scoped factory.
+pub struct ScopedContainer<T>(pub(crate) OnceCell<T>);
+
+impl<T> ScopeCache for ScopedFactoryContainer<T> {
+    type Cache = ScopedContainer<T>;
+}
+
+impl<T> CollectMany for ScopedFactoryContainer<T> {}
+
+impl<T> ScopeCache for ScopedContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for ScopedContainer<T> {}
+
+impl<T> Default for ScopedContainer<T> {
+    fn default() -> Self {
+        Self(OnceCell::new())
+    }
+}
+
+impl<'a, Parent, Scope, ConstructorInfer, T, Infer>
+    Injector<&'a T, (Infer, ScopedFactoryStrategy<ConstructorInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: DepsListGetRef<ScopedContainer<T>, Infer>,
+    Self: ListInjector<T::Dependencies<'a>, ConstructorInfer>,
+    T: ConstructorFactory,
+{
+    /// Inject an immutable reference to the instance cached in the child scope building it first
+    /// if it wasn't injected from the scope before.
+    fn inject(self) -> &'a T {
+        self.get().0.get_or_init(|| T::build(self.inject_list()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+
+    #[test]
+    fn test_inject() {
+        struct BuildsCount(Cell<usize>);
+
+        struct Session;
+
+        impl ConstructorFactory for Session {
+            type Dependencies<'a> = (&'a BuildsCount, ());
+
+            fn build((builds_count, ()): Self::Dependencies<'_>) -> Self {
+                builds_count.0.set(builds_count.0.get() + 1);
+                Self
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(BuildsCount(Cell::new(0)))
+            .with_scoped_factory::<Session>();
+        let builds_count: &BuildsCount = (&container).inject();
+
+        let scope = container.scope();
+        let _session: &Session = (&scope).inject();
+        let _session: &Session = (&scope).inject();
+        assert_eq!(builds_count.0.get(), 1);
+
+        let scope = container.scope();
+        let _session: &Session = (&scope).inject();
+        assert_eq!(builds_count.0.get(), 2);
+    }
+}
@@ -1,7 +1,10 @@
-use super::Injector;
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector, ListInjector,
+};
 use crate::{
     container::DependencyContainer,
-    deps_list::{DepsListGetMut, DepsListGetRef, DepsListRemove},
+    deps_list::{DepsListGetMut, DepsListGetRef, DepsListRemove, DepsListSplitMut},
 };
 use core::convert::Infallible;
 
@@ -10,6 +13,12 @@ pub struct SingletonStrategy(Infallible);
 /// A container for holding a singleton instance of a dependency.
 pub struct SingletonContainer<T>(pub(crate) T);
 
+impl<T> ScopeCache for SingletonContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for SingletonContainer<T> {}
+
 impl<Parent, Scope, T, Infer>
     Injector<
         (
@@ -56,9 +65,115 @@ where
     }
 }
 
+/// A container from which a list of singletons is removed by value.
+pub trait SingletonList<T, Infer> {
+    /// The container left after removing the singletons.
+    type Remainder;
+
+    /// Remove the singletons from the container.
+    fn remove_list(self) -> (T, Self::Remainder);
+}
+
+impl<C> SingletonList<(), ()> for C {
+    type Remainder = C;
+
+    fn remove_list(self) -> ((), C) {
+        ((), self)
+    }
+}
+
+impl<C, T, Tail, Infer, TailInfer> SingletonList<(T, Tail), (Infer, TailInfer)> for C
+where
+    C: DepsListRemove<SingletonContainer<T>, Infer>,
+    C::Remainder: SingletonList<Tail, TailInfer>,
+{
+    type Remainder = <C::Remainder as SingletonList<Tail, TailInfer>>::Remainder;
+
+    fn remove_list(self) -> ((T, Tail), Self::Remainder) {
+        let (singleton, remainder) = self.remove();
+        let (tail, remainder) = remainder.remove_list();
+
+        ((singleton.0, tail), remainder)
+    }
+}
+
+impl<Parent, Scope, T, Infer>
+    ListInjector<(T, <Self as SingletonList<T, Infer>>::Remainder), (Infer, SingletonStrategy)>
+    for DependencyContainer<Parent, Scope>
+where
+    Self: SingletonList<T, Infer>,
+{
+    /// Split the container into a list of dependencies(singletons) and a new container with the
+    /// dependencies removed.
+    fn inject_list(self) -> (T, <Self as SingletonList<T, Infer>>::Remainder) {
+        self.remove_list()
+    }
+}
+
+/// A list of mutable references to containers from which disjoint references to singletons are
+/// removed.
+///
+/// Every singleton is removed from the list once it is referenced, so requesting the same singleton
+/// twice doesn't compile:
+///
+/// ```rust,compile_fail
+/// use rustyinject::{DependencyContainer, Deps, injector::ListInjector};
+///
+/// struct Cache;
+///
+/// let mut container = DependencyContainer::default().with_singleton(Cache);
+/// let _: Deps![&mut Cache, &Cache] = (&mut container).inject_list();
+/// ```
+pub trait SingletonRefList<T, Infer> {
+    /// Remove references to the singletons from the list.
+    fn remove_refs(self) -> T;
+}
+
+impl<List> SingletonRefList<(), ()> for List {
+    fn remove_refs(self) {}
+}
+
+impl<'a, List, T, Tail, Infer, TailInfer> SingletonRefList<(&'a mut T, Tail), (Infer, TailInfer)>
+    for List
+where
+    List: DepsListRemove<&'a mut SingletonContainer<T>, Infer>,
+    List::Remainder: SingletonRefList<Tail, TailInfer>,
+{
+    fn remove_refs(self) -> (&'a mut T, Tail) {
+        let (singleton, remainder) = self.remove();
+        (&mut singleton.0, remainder.remove_refs())
+    }
+}
+
+impl<'a, List, T, Tail, Infer, TailInfer> SingletonRefList<(&'a T, Tail), (Infer, TailInfer)>
+    for List
+where
+    List: DepsListRemove<&'a mut SingletonContainer<T>, Infer>,
+    List::Remainder: SingletonRefList<Tail, TailInfer>,
+{
+    fn remove_refs(self) -> (&'a T, Tail) {
+        let (singleton, remainder) = self.remove();
+        (&singleton.0, remainder.remove_refs())
+    }
+}
+
+impl<Parent, Scope, T, Infer> ListInjector<T, (Infer, SingletonStrategy)>
+    for &mut DependencyContainer<Parent, Scope>
+where
+    Self: DepsListSplitMut,
+    <Self as DepsListSplitMut>::Split: SingletonRefList<T, Infer>,
+{
+    /// Inject a list of disjoint mutable and immutable references to the dependencies(singletons)
+    /// by consuming a mutable reference to the container.
+    fn inject_list(self) -> T {
+        self.split_mut().remove_refs()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{deps_pat, Deps};
 
     #[test]
     fn test_inject() {
@@ -73,4 +188,74 @@ mod tests {
         let _app: &mut App = (&mut container).inject();
         let (_app, _container): (App, _) = container.inject();
     }
+
+    #[test]
+    fn test_inject_list() {
+        struct Database;
+        struct Cache;
+        struct Metrics;
+
+        let container = DependencyContainer::default()
+            .with_singleton(Database)
+            .with_singleton(Cache)
+            .with_singleton(Metrics);
+
+        let (deps_pat![_metrics, _db], container): (Deps![Metrics, Database], _) =
+            container.inject_list();
+        let _cache: &Cache = (&container).inject();
+    }
+
+    #[test]
+    fn test_inject_disjoint() {
+        struct Cache(u32);
+        struct Metrics(u32);
+        struct Config(u32);
+
+        let parent = DependencyContainer::default().with_singleton(Config(1));
+        let mut container = DependencyContainer::new(parent)
+            .with_singleton(Cache(0))
+            .with_singleton(Metrics(0));
+
+        let deps_pat![cache, config, metrics]: Deps![&mut Cache, &Config, &mut Metrics] =
+            (&mut container).inject_list();
+        cache.0 += config.0;
+        metrics.0 += config.0;
+
+        let cache: &Cache = (&container).inject();
+        let metrics: &Metrics = (&container).inject();
+        assert_eq!((cache.0, metrics.0), (1, 1));
+    }
+
+    #[test]
+    fn test_inject_disjoint_parents() {
+        struct Cache(u32);
+        struct Metrics(u32);
+        struct Config(u32);
+
+        let parent = DependencyContainer::default().with_singleton(Config(1));
+        let mut container = DependencyContainer::new(&parent)
+            .with_singleton(Cache(0))
+            .with_singleton(Metrics(0));
+        let deps_pat![cache, metrics]: Deps![&mut Cache, &Metrics] = (&mut container).inject_list();
+        cache.0 += metrics.0 + 1;
+        let cache: &Cache = (&container).inject();
+        assert_eq!(cache.0, 1);
+
+        let mut scope = parent.scope().with_singleton(Cache(0));
+        let deps_pat![cache]: Deps![&mut Cache] = (&mut scope).inject_list();
+        cache.0 += 1;
+
+        let mut parent = DependencyContainer::default().with_singleton(Config(1));
+        let mut container = DependencyContainer::new(&mut parent).with_singleton(Cache(0));
+        let deps_pat![cache, config]: Deps![&mut Cache, &mut Config] =
+            (&mut container).inject_list();
+        cache.0 += config.0;
+        config.0 += 1;
+
+        let mut container = DependencyContainer::new(Box::new(parent)).with_singleton(Metrics(0));
+        let deps_pat![metrics, config]: Deps![&mut Metrics, &Config] =
+            (&mut container).inject_list();
+        metrics.0 += config.0;
+        assert_eq!(metrics.0, 2);
+    }
 }
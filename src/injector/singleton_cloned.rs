@@ -11,7 +11,7 @@ where
     DependencyContainer<Parent, Scope>: DepsListGetRef<SingletonContainer<T>, Infer>,
     T: Clone,
 {
-    /// Inject a dependency(singleton) by cloning it from the container
+    /// Inject a dependency(singleton) by cloning it from the container.
     fn inject(self) -> T {
         self.get().0.clone()
     }
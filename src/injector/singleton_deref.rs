@@ -0,0 +1,150 @@
+use super::{containers::SingletonContainer, Injector};
+use crate::{
+    container::{CurrentScope, DependencyContainer, ParentScope},
+    deps_list::{Last, Next},
+};
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+use core::{convert::Infallible, ops::Deref};
+
+/// A marker struct used to signify the strategy of injecting an immutable reference to the target
+/// of a smart pointer singleton in dependency injection.
+pub struct SingletonDerefStrategy(Infallible);
+
+/// Trait for getting immutable references to the targets of smart pointer singletons like
+/// `Box<T>`, `Arc<T>` or [`String`](alloc::string::String) in the heterogeneously-typed list.
+///
+/// Unlike looking up a `SingletonContainer<P>` with `P: Deref<Target = T>`, the target is tied to
+/// the element, so the index can be inferred from the target alone.
+pub trait SingletonDerefList<T: ?Sized, Idx> {
+    /// Get an immutable reference to the target of a singleton.
+    fn get_deref(&self) -> &T;
+}
+
+impl<Pointer, Tail> SingletonDerefList<Pointer::Target, Last>
+    for (SingletonContainer<Pointer>, Tail)
+where
+    Pointer: Deref,
+{
+    fn get_deref(&self) -> &Pointer::Target {
+        &self.0 .0
+    }
+}
+
+impl<Head, Tail, T, Idx> SingletonDerefList<T, Next<Idx>> for (Head, Tail)
+where
+    Tail: SingletonDerefList<T, Idx>,
+    T: ?Sized,
+{
+    fn get_deref(&self) -> &T {
+        self.1.get_deref()
+    }
+}
+
+impl<D, T, Idx> SingletonDerefList<T, Idx> for &D
+where
+    D: SingletonDerefList<T, Idx>,
+    T: ?Sized,
+{
+    fn get_deref(&self) -> &T {
+        (**self).get_deref()
+    }
+}
+
+impl<D, T, Idx> SingletonDerefList<T, Idx> for Box<D>
+where
+    D: SingletonDerefList<T, Idx>,
+    T: ?Sized,
+{
+    fn get_deref(&self) -> &T {
+        (**self).get_deref()
+    }
+}
+
+impl<D, T, Idx> SingletonDerefList<T, Idx> for Rc<D>
+where
+    D: SingletonDerefList<T, Idx>,
+    T: ?Sized,
+{
+    fn get_deref(&self) -> &T {
+        (**self).get_deref()
+    }
+}
+
+impl<D, T, Idx> SingletonDerefList<T, Idx> for Arc<D>
+where
+    D: SingletonDerefList<T, Idx>,
+    T: ?Sized,
+{
+    fn get_deref(&self) -> &T {
+        (**self).get_deref()
+    }
+}
+
+impl<Parent, Scope, T, Idx> SingletonDerefList<T, (CurrentScope, Idx)>
+    for DependencyContainer<Parent, Scope>
+where
+    Scope: SingletonDerefList<T, Idx>,
+    T: ?Sized,
+{
+    fn get_deref(&self) -> &T {
+        self.scope.get_deref()
+    }
+}
+
+impl<Parent, Scope, T, Idx, Subscope> SingletonDerefList<T, (ParentScope<Subscope>, Idx)>
+    for DependencyContainer<Parent, Scope>
+where
+    Parent: SingletonDerefList<T, (Subscope, Idx)>,
+    T: ?Sized,
+{
+    fn get_deref(&self) -> &T {
+        self.parent.get_deref()
+    }
+}
+
+impl<'a, Parent, Scope, T, Infer> Injector<&'a T, (Infer, SingletonDerefStrategy)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    DependencyContainer<Parent, Scope>: SingletonDerefList<T, Infer>,
+    T: ?Sized,
+{
+    /// Inject an immutable reference to the target of the dependency(singleton) by dereferencing
+    /// it.
+    fn inject(self) -> &'a T {
+        self.get_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn test_inject() {
+        trait Repository {}
+        struct InMemoryRepository;
+        impl Repository for InMemoryRepository {}
+
+        struct Config;
+        struct Cache;
+
+        let repository: Box<dyn Repository> = Box::new(InMemoryRepository);
+        let parent = DependencyContainer::default().with_singleton(Rc::new(Cache));
+        let container = DependencyContainer::new(&parent)
+            .with_singleton(repository)
+            .with_singleton(String::from("app"))
+            .with_singleton(Arc::new(Config));
+
+        let _repository: &dyn Repository = (&container).inject();
+        let name: &str = (&container).inject();
+        assert_eq!(name, "app");
+        let _config: &Config = (&container).inject();
+        let _config: &Arc<Config> = (&container).inject();
+        let _cache: &Cache = (&container).inject();
+
+        let parent = Arc::new(DependencyContainer::default().with_singleton(Box::new(Config)));
+        let container = DependencyContainer::new(Arc::clone(&parent));
+        let _config: &Config = (&container).inject();
+    }
+}
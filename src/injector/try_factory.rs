@@ -0,0 +1,213 @@
+use super::{
+    containers::{CollectMany, ScopeCache},
+    Injector, TryInjector, TryListInjector,
+};
+use crate::{DependencyContainer, DepsListGetRef, ResolveError};
+use core::{convert::Infallible, marker::PhantomData};
+
+/// A trait representing a factory for creating an instance from dependencies that can fail.
+pub trait TryFactory {
+    /// A result of the factory [`build`](TryFactory::build) method.
+    type Result;
+    /// An error of the factory [`build`](TryFactory::build) method.
+    type Error;
+    /// Dependencies of the factory.
+    type Dependencies<'a>;
+
+    /// Build result from dependencies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the result can't be built.
+    fn build(&self, dependencies: Self::Dependencies<'_>) -> Result<Self::Result, Self::Error>;
+}
+
+/// A trait representing a struct that builds from a constructor that can fail.
+pub trait TryConstructorFactory: Sized {
+    /// An error of the constructor.
+    type Error;
+    /// Dependencies of the factory.
+    type Dependencies<'a>;
+
+    /// Creates a new instance from dependencies.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instance can't be created.
+    fn build(dependencies: Self::Dependencies<'_>) -> Result<Self, Self::Error>;
+}
+
+/// A marker struct used to signify that an infallible strategy is used for fallible dependency
+/// injection.
+pub struct InfallibleStrategy<Infer>(PhantomData<Infer>, Infallible);
+/// A marker struct used to signify the fallible factory strategy in dependency injection.
+pub struct TryFactoryStrategy<F, FactoryInfer>(PhantomData<(F, FactoryInfer)>, Infallible);
+/// A marker struct used to signify the fallible constructor factory strategy in dependency
+/// injection.
+pub struct TryConstructorFactoryStrategy<ConstructorInfer>(
+    PhantomData<ConstructorInfer>,
+    Infallible,
+);
+
+/// A container for holding a [`TryFactory`] instance and its result type.
+pub struct TryFactoryContainer<F, FactoryResult>(
+    pub(crate) F,
+    pub(crate) PhantomData<FactoryResult>,
+);
+/// A container for holding type of struct that can be built from specified dependencies with a
+/// constructor that can fail.
+pub struct TryConstructorFactoryContainer<T>(pub(crate) PhantomData<T>);
+
+impl<F, FactoryResult> ScopeCache for TryFactoryContainer<F, FactoryResult> {
+    type Cache = ();
+}
+
+impl<F, FactoryResult> CollectMany for TryFactoryContainer<F, FactoryResult> {}
+
+impl<T> ScopeCache for TryConstructorFactoryContainer<T> {
+    type Cache = ();
+}
+
+impl<T> CollectMany for TryConstructorFactoryContainer<T> {}
+
+impl<C, T, E, Infer> TryInjector<T, E, InfallibleStrategy<Infer>> for C
+where
+    C: Injector<T, Infer>,
+{
+    /// Inject a dependency that is always injected successfully.
+    fn try_inject(self) -> Result<T, ResolveError<E>> {
+        Ok(self.inject())
+    }
+}
+
+impl<'a, Parent, Scope, F, FactoryInfer, T, E, Infer>
+    TryInjector<T, E, (Infer, TryFactoryStrategy<F, FactoryInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    Self: DepsListGetRef<TryFactoryContainer<F, T>, Infer>
+        + TryListInjector<F::Dependencies<'a>, E, FactoryInfer>,
+    F: TryFactory<Result = T>,
+    E: From<F::Error>,
+{
+    fn try_inject(self) -> Result<T, ResolveError<E>> {
+        let factory = &self.get().0;
+        let dependencies = self.try_inject_list().map_err(ResolveError::within::<T>)?;
+        factory
+            .build(dependencies)
+            .map_err(|error| ResolveError::new::<T>(error.into()))
+    }
+}
+
+impl<'a, Parent, Scope, ConstructorInfer, T, E, Infer>
+    TryInjector<T, E, (Infer, TryConstructorFactoryStrategy<ConstructorInfer>)>
+    for &'a DependencyContainer<Parent, Scope>
+where
+    Self: DepsListGetRef<TryConstructorFactoryContainer<T>, Infer>
+        + TryListInjector<T::Dependencies<'a>, E, ConstructorInfer>,
+    T: TryConstructorFactory,
+    E: From<T::Error>,
+{
+    fn try_inject(self) -> Result<T, ResolveError<E>> {
+        let dependencies = self.try_inject_list().map_err(ResolveError::within::<T>)?;
+        T::build(dependencies).map_err(|error| ResolveError::new::<T>(error.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::num::ParseIntError;
+
+    #[test]
+    fn test_try_inject() {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Error {
+            Parse,
+            Connect,
+        }
+
+        impl From<ParseIntError> for Error {
+            fn from(_error: ParseIntError) -> Self {
+                Self::Parse
+            }
+        }
+
+        struct ConnectError;
+
+        impl From<ConnectError> for Error {
+            fn from(_error: ConnectError) -> Self {
+                Self::Connect
+            }
+        }
+
+        struct RawPort(&'static str);
+
+        struct Port(u16);
+
+        impl TryConstructorFactory for Port {
+            type Error = ParseIntError;
+            type Dependencies<'a> = (&'a RawPort, ());
+
+            fn build((raw_port, ()): Self::Dependencies<'_>) -> Result<Self, ParseIntError> {
+                raw_port.0.parse().map(Self)
+            }
+        }
+
+        struct Connection;
+
+        struct ConnectionFactory;
+
+        impl TryFactory for ConnectionFactory {
+            type Result = Connection;
+            type Error = ConnectError;
+            type Dependencies<'a> = (Port, ());
+
+            fn build(
+                &self,
+                (port, ()): Self::Dependencies<'_>,
+            ) -> Result<Connection, ConnectError> {
+                if port.0 == 0 {
+                    Err(ConnectError)
+                } else {
+                    Ok(Connection)
+                }
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_singleton(RawPort("8080"))
+            .with_try_constructor_factory::<Port>()
+            .with_try_factory(ConnectionFactory);
+        let port: Result<Port, ResolveError<Error>> = (&container).try_inject();
+        assert_eq!(port.map(|port| port.0).ok(), Some(8080));
+        let connection: Result<Connection, ResolveError<Error>> = (&container).try_inject();
+        let _connection = connection.unwrap();
+
+        let container = DependencyContainer::default()
+            .with_singleton(RawPort("port"))
+            .with_try_constructor_factory::<Port>()
+            .with_try_factory(ConnectionFactory);
+        let error: ResolveError<Error> = (&container)
+            .try_inject()
+            .map(|_: Connection| ())
+            .unwrap_err();
+        assert_eq!(
+            error.path(),
+            [
+                core::any::type_name::<Connection>(),
+                core::any::type_name::<Port>()
+            ]
+        );
+        assert_eq!(error.into_source(), Error::Parse);
+
+        let container = DependencyContainer::default()
+            .with_singleton(RawPort("0"))
+            .with_try_constructor_factory::<Port>()
+            .with_try_factory(ConnectionFactory);
+        let connection: Result<Connection, ResolveError<Error>> = (&container).try_inject();
+        assert_eq!(
+            connection.map_err(ResolveError::into_source).err(),
+            Some(Error::Connect)
+        );
+    }
+}
@@ -5,6 +5,14 @@
 //! - Store singleton instances and provide them.
 //! - Provide cloned instances of singletons.
 //! - Create instances using factory methods.
+//! - Create instances using factories that can fail.
+//! - Create instances using asynchronous factories, independently of a runtime.
+//! - Lazily create singletons on the first injection.
+//! - Create instances once per child scope, like a single request.
+//! - Provide optional dependencies that may be declared absent, or their default values instead.
+//! - Provide all registered implementations of a trait at once.
+//! - Tell apart dependencies of the same type by tags.
+//! - Bind traits to their implementations.
 //!
 //! ## Usage
 //!
@@ -32,6 +40,11 @@
 //!
 //! let my_service: MyService = (&container).inject();
 //! ```
+//!
+//! ## Features
+//!
+//! - `derive`: derive macros that implement factory traits from struct fields.
+//! - `async`: singletons behind tokio locks.
 
 #![deny(
     warnings,
@@ -78,20 +91,39 @@
     clippy::unwrap_used,
     clippy::unreachable,
     clippy::impl_trait_in_params,
+    clippy::allow_attributes_without_reason,
+    clippy::field_scoped_visibility_modifiers,
+    clippy::arbitrary_source_item_ordering,
+    clippy::unused_trait_names,
+    clippy::allow_attributes,
+    clippy::redundant_test_prefix,
 )]
 #![forbid(unreachable_pub, missing_docs)]
 
 extern crate alloc;
+// Allows code generated by derive macros to refer to the crate inside of it.
+extern crate self as rustyinject;
 
 mod container;
 mod deps_list;
+mod error;
 pub mod injector;
+mod module;
 
 pub mod indecies {
     //! Indecies for indexing [`DepsList`](super::deps_list::DepsList) and
-    //! [`DependencyContainer`](super::container::DependencyContainer)
+    //! [`DependencyContainer`](super::container::DependencyContainer).
     pub use super::container::{CurrentScope, ParentScope};
     pub use super::deps_list::{Last, Next};
 }
+#[doc(hidden)]
+pub mod __private {
+    //! Items used by code generated with macros. Not a public API.
+    pub use alloc::{boxed::Box, sync::Arc};
+}
 pub use container::DependencyContainer;
-pub use deps_list::{DepsList, DepsListGetMut, DepsListGetRef, DepsListRemove};
+pub use deps_list::{
+    DepsList, DepsListGetMut, DepsListGetRef, DepsListRemove, DepsListSplitMut, Prepended,
+};
+pub use error::ResolveError;
+pub use module::Module;
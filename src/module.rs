@@ -0,0 +1,109 @@
+use crate::DependencyContainer;
+
+/// A reusable group of registrations that can be added to a container at once with
+/// [`DependencyContainer::with_module`].
+///
+/// ```rust
+/// use rustyinject::{
+///     injector::containers::SingletonContainer, DependencyContainer, DepsList, Module, Prepended,
+/// };
+///
+/// struct Database;
+/// struct Cache;
+///
+/// struct StorageModule;
+///
+/// impl<Parent, Scope> Module<Parent, Scope> for StorageModule
+/// where
+///     Scope: DepsList,
+/// {
+///     type Output = DependencyContainer<
+///         Parent,
+///         Prepended<Prepended<Scope, SingletonContainer<Database>>, SingletonContainer<Cache>>,
+///     >;
+///
+///     fn register(self, container: DependencyContainer<Parent, Scope>) -> Self::Output {
+///         container.with_singleton(Database).with_singleton(Cache)
+///     }
+/// }
+///
+/// let container = DependencyContainer::default().with_module(StorageModule);
+/// ```
+pub trait Module<Parent, Scope> {
+    /// Container with registrations of the module added.
+    type Output;
+
+    /// Add registrations of the module to the container.
+    fn register(self, container: DependencyContainer<Parent, Scope>) -> Self::Output;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        injector::{
+            containers::{ConstructorFactoryContainer, SingletonContainer},
+            factories::ConstructorFactory,
+            Injector,
+        },
+        DepsList, Prepended,
+    };
+
+    #[test]
+    fn test_with_module() {
+        #[derive(Clone)]
+        struct Database;
+        struct Cache;
+
+        struct Posts;
+
+        impl ConstructorFactory for Posts {
+            type Dependencies<'a> = (&'a Database, &'a Cache);
+
+            fn build(_dependencies: Self::Dependencies<'_>) -> Self {
+                Self
+            }
+        }
+
+        struct DatabaseModule(Database);
+
+        impl<Parent, Scope> Module<Parent, Scope> for DatabaseModule
+        where
+            Scope: DepsList,
+        {
+            type Output =
+                DependencyContainer<Parent, Prepended<Scope, SingletonContainer<Database>>>;
+
+            fn register(self, container: DependencyContainer<Parent, Scope>) -> Self::Output {
+                container.with_singleton(self.0)
+            }
+        }
+
+        struct PostsModule;
+
+        impl<Parent, Scope> Module<Parent, Scope> for PostsModule
+        where
+            Scope: DepsList,
+        {
+            type Output = DependencyContainer<
+                Parent,
+                Prepended<
+                    Prepended<Scope, SingletonContainer<Cache>>,
+                    ConstructorFactoryContainer<Posts>,
+                >,
+            >;
+
+            fn register(self, container: DependencyContainer<Parent, Scope>) -> Self::Output {
+                container
+                    .with_singleton(Cache)
+                    .with_constructor_factory::<Posts>()
+            }
+        }
+
+        let container = DependencyContainer::default()
+            .with_module(DatabaseModule(Database))
+            .with_module(PostsModule);
+
+        let _posts: Posts = (&container).inject();
+    }
+}